@@ -0,0 +1,470 @@
+/// The index of a wire in the circuit, each wire is driven by the gate of the same index
+pub type Wire = usize;
+
+/// A logic gate, which drives a single wire from the wires it reads
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gate {
+    /// The input of the circuit at the given index
+    Input(usize),
+    /// The negation of the given wire
+    Not(Wire),
+    /// The conjunction of the two given wires
+    And(Wire, Wire),
+    /// The disjunction of the two given wires
+    Or(Wire, Wire),
+    /// The exclusive disjunction of the two given wires
+    Xor(Wire, Wire),
+}
+
+impl Gate {
+    fn get_operands(&self) -> Vec<Wire> {
+        match *self {
+            Gate::Input(_) => vec![],
+            Gate::Not(a) => vec![a],
+            Gate::And(a, b) | Gate::Or(a, b) | Gate::Xor(a, b) => vec![a, b],
+        }
+    }
+
+    fn eval(&self, values: &[bool], inputs: &[bool]) -> bool {
+        match *self {
+            Gate::Input(i) => inputs[i],
+            Gate::Not(a) => !values[a],
+            Gate::And(a, b) => values[a] & values[b],
+            Gate::Or(a, b) => values[a] | values[b],
+            Gate::Xor(a, b) => values[a] ^ values[b],
+        }
+    }
+}
+
+/// A combinational circuit made of gates connected by wires,
+/// a gate can only read the wires of the gates added before it so there is no loop
+#[derive(Debug, Clone, Default)]
+pub struct Circuit {
+    gates: Vec<Gate>,
+    inputs: Vec<Wire>,
+    outputs: Vec<Wire>,
+}
+
+impl Circuit {
+    /// Get an empty circuit, without any input, gate or output
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a new input to the circuit and return its wire
+    pub fn add_input(&mut self) -> Wire {
+        let wire: Wire = self.add_gate(Gate::Input(self.inputs.len()));
+
+        self.inputs.push(wire);
+        wire
+    }
+
+    /// Mark the wire as the next output of the circuit
+    pub fn add_output(&mut self, wire: Wire) {
+        self.check_wire(wire);
+        self.outputs.push(wire);
+    }
+
+    /// Add a NOT gate to the circuit and return its wire
+    /// # Arguments
+    /// * `a` - The wire to negate
+    /// # Panics
+    /// If the wire is not in the circuit
+    pub fn not(&mut self, a: Wire) -> Wire {
+        self.add_gate(Gate::Not(a))
+    }
+
+    /// Add an AND gate to the circuit and return its wire
+    /// # Arguments
+    /// * `a` - The first wire the gate reads
+    /// * `b` - The second wire the gate reads
+    /// # Panics
+    /// If a wire is not in the circuit
+    pub fn and(&mut self, a: Wire, b: Wire) -> Wire {
+        self.add_gate(Gate::And(a, b))
+    }
+
+    /// Add an OR gate to the circuit and return its wire
+    /// # Arguments
+    /// * `a` - The first wire the gate reads
+    /// * `b` - The second wire the gate reads
+    /// # Panics
+    /// If a wire is not in the circuit
+    pub fn or(&mut self, a: Wire, b: Wire) -> Wire {
+        self.add_gate(Gate::Or(a, b))
+    }
+
+    /// Add a XOR gate to the circuit and return its wire
+    /// # Arguments
+    /// * `a` - The first wire the gate reads
+    /// * `b` - The second wire the gate reads
+    /// # Panics
+    /// If a wire is not in the circuit
+    pub fn xor(&mut self, a: Wire, b: Wire) -> Wire {
+        self.add_gate(Gate::Xor(a, b))
+    }
+
+    /// Get the gates of the circuit, the index of a gate is the index of its wire
+    pub fn get_gates(&self) -> &Vec<Gate> {
+        &self.gates
+    }
+
+    /// Get the wires of the inputs, in the order they were added
+    pub fn get_inputs(&self) -> &Vec<Wire> {
+        &self.inputs
+    }
+
+    /// Get the wires of the outputs, in the order they were added
+    pub fn get_outputs(&self) -> &Vec<Wire> {
+        &self.outputs
+    }
+
+    /// Get the number of logic gates, the inputs are not counted
+    pub fn gate_count(&self) -> usize {
+        self.gates
+            .iter()
+            .filter(|gate: &&Gate| !matches!(gate, Gate::Input(_)))
+            .count()
+    }
+
+    /// Get the depth of the circuit, which is the number of gates
+    /// on the longest path from an input to an output (the critical path)
+    pub fn depth(&self) -> usize {
+        let depths: Vec<usize> = self.get_depths();
+
+        self.outputs
+            .iter()
+            .map(|wire: &Wire| depths[*wire])
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Get the number of gate inputs driven by the wire, an output of the circuit counts as one
+    pub fn fan_out(&self, wire: Wire) -> usize {
+        self.check_wire(wire);
+        let readers: usize = self
+            .gates
+            .iter()
+            .flat_map(|gate: &Gate| gate.get_operands())
+            .filter(|operand: &Wire| *operand == wire)
+            .count();
+
+        readers + self.outputs.iter().filter(|output: &&Wire| **output == wire).count()
+    }
+
+    /// Get the largest fan-out of the wires of the circuit
+    pub fn max_fan_out(&self) -> usize {
+        (0..self.gates.len())
+            .map(|wire: Wire| self.fan_out(wire))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Get the values of the outputs once the circuit has settled
+    /// # Arguments
+    /// * `inputs` - The value of each input of the circuit
+    /// # Panics
+    /// If the number of values doesn't match the number of inputs
+    pub fn simulate(&self, inputs: &[bool]) -> Vec<bool> {
+        self.check_inputs(inputs);
+        let mut values: Vec<bool> = Vec::with_capacity(self.gates.len());
+
+        // the gates only read the previous wires, so a single pass in order is enough
+        for gate in self.gates.iter() {
+            let value: bool = gate.eval(&values, inputs);
+
+            values.push(value);
+        }
+
+        self.outputs.iter().map(|wire: &Wire| values[*wire]).collect()
+    }
+
+    /// Start a cycle-accurate simulation of the circuit with the inputs set and all the other wires unset
+    /// # Arguments
+    /// * `inputs` - The value of each input of the circuit
+    /// # Panics
+    /// If the number of values doesn't match the number of inputs
+    pub fn start_simulation<'a>(&'a self, inputs: &'a [bool]) -> Simulation<'a> {
+        self.check_inputs(inputs);
+        let values: Vec<bool> = self
+            .gates
+            .iter()
+            .map(|gate: &Gate| matches!(gate, Gate::Input(i) if inputs[*i]))
+            .collect();
+
+        Simulation {
+            circuit: self,
+            inputs,
+            values,
+            cycles: 0,
+        }
+    }
+
+    fn add_gate(&mut self, gate: Gate) -> Wire {
+        for operand in gate.get_operands() {
+            self.check_wire(operand);
+        }
+        self.gates.push(gate);
+
+        self.gates.len() - 1
+    }
+
+    fn get_depths(&self) -> Vec<usize> {
+        let mut depths: Vec<usize> = Vec::with_capacity(self.gates.len());
+
+        for gate in self.gates.iter() {
+            let depth: usize = match gate {
+                Gate::Input(_) => 0,
+                _ => {
+                    1 + gate
+                        .get_operands()
+                        .iter()
+                        .map(|wire: &Wire| depths[*wire])
+                        .max()
+                        .unwrap_or(0)
+                }
+            };
+
+            depths.push(depth);
+        }
+
+        depths
+    }
+
+    fn check_wire(&self, wire: Wire) {
+        if wire >= self.gates.len() {
+            panic!("Invalid wire");
+        }
+    }
+
+    fn check_inputs(&self, inputs: &[bool]) {
+        if inputs.len() != self.inputs.len() {
+            panic!("Invalid number of inputs");
+        }
+    }
+}
+
+/// A cycle-accurate simulation where each gate takes one cycle to update its wire
+#[derive(Debug, Clone)]
+pub struct Simulation<'a> {
+    circuit: &'a Circuit,
+    inputs: &'a [bool],
+    values: Vec<bool>,
+    cycles: usize,
+}
+
+impl Simulation<'_> {
+    /// Run one cycle: every gate reads the values of the wires from the previous cycle,
+    /// return true if a wire has changed
+    pub fn step(&mut self) -> bool {
+        let values: Vec<bool> = self
+            .circuit
+            .gates
+            .iter()
+            .map(|gate: &Gate| gate.eval(&self.values, self.inputs))
+            .collect();
+        let changed: bool = values != self.values;
+
+        self.values = values;
+        self.cycles += 1;
+        changed
+    }
+
+    /// Run cycles until no wire changes and return the number of cycles
+    /// in which a wire has changed, it is never more than the depth of the circuit
+    pub fn settle(&mut self) -> usize {
+        let mut last_change: usize = self.cycles;
+
+        while self.step() {
+            last_change = self.cycles;
+        }
+
+        last_change
+    }
+
+    /// Get the number of cycles run since the start of the simulation
+    pub fn get_cycles(&self) -> usize {
+        self.cycles
+    }
+
+    /// Get the current values of the outputs
+    pub fn get_outputs(&self) -> Vec<bool> {
+        self.circuit.outputs.iter().map(|wire: &Wire| self.values[*wire]).collect()
+    }
+}
+
+/// Build a ripple-carry adder of the given number of bits: each full adder
+/// waits for the carry of the previous one, so the depth grows linearly.
+/// The inputs are the bits of a then the bits of b from the lowest one,
+/// the outputs are the bits of the sum then the carry out.
+pub fn ripple_carry_adder(bits: usize) -> Circuit {
+    let (mut circuit, a, b) = get_adder_inputs(bits);
+    let mut carry: Option<Wire> = None;
+
+    for i in 0..bits {
+        /*
+            full adder:
+            sum = a ^ b ^ carry
+            carry out = (a & b) | ((a ^ b) & carry)
+            the first bit has no carry in, so it's a half adder
+        */
+        let propagate: Wire = circuit.xor(a[i], b[i]);
+        let generate: Wire = circuit.and(a[i], b[i]);
+
+        carry = Some(match carry {
+            Some(carry) => {
+                let sum: Wire = circuit.xor(propagate, carry);
+                let carried: Wire = circuit.and(propagate, carry);
+
+                circuit.add_output(sum);
+                circuit.or(generate, carried)
+            }
+            None => {
+                circuit.add_output(propagate);
+                generate
+            }
+        });
+    }
+    if let Some(carry) = carry {
+        circuit.add_output(carry);
+    }
+
+    circuit
+}
+
+/// Build a carry-lookahead adder of the given number of bits made of 4 bits blocks:
+/// inside a block every carry is computed directly from the generate and propagate
+/// signals and the carry in of the block, only the carries between the blocks ripple.
+/// The inputs and outputs are the same as the ripple-carry adder.
+pub fn carry_lookahead_adder(bits: usize) -> Circuit {
+    let (mut circuit, a, b) = get_adder_inputs(bits);
+    let generate: Vec<Wire> = (0..bits).map(|i: usize| circuit.and(a[i], b[i])).collect();
+    let propagate: Vec<Wire> = (0..bits).map(|i: usize| circuit.xor(a[i], b[i])).collect();
+    let mut carries: Vec<Option<Wire>> = vec![None];
+
+    for start in (0..bits).step_by(4) {
+        let block_in: Option<Wire> = carries[start];
+
+        for i in start..(start + 4).min(bits) {
+            /*
+                c(i + 1) = g(i) | p(i) g(i - 1) | p(i) p(i - 1) g(i - 2) | ... | p(i) ... p(start) c(start)
+                each term is an AND of the propagate signals down to a generate signal
+                (or the carry in of the block), and the carry is the OR of the terms
+            */
+            let mut carry: Wire = generate[i];
+
+            for j in (start..i).rev() {
+                let term: Wire = get_and_chain(&mut circuit, &propagate[j + 1..=i], generate[j]);
+
+                carry = circuit.or(carry, term);
+            }
+            if let Some(block_in) = block_in {
+                let term: Wire = get_and_chain(&mut circuit, &propagate[start..=i], block_in);
+
+                carry = circuit.or(carry, term);
+            }
+            carries.push(Some(carry));
+        }
+    }
+    add_sum_outputs(&mut circuit, &propagate, &carries);
+
+    circuit
+}
+
+/// Build a Kogge-Stone adder of the given number of bits, a parallel prefix adder
+/// which combines the generate and propagate signals of the spans of 1, 2, 4... bits,
+/// so the depth grows with the logarithm of the number of bits.
+/// The inputs and outputs are the same as the ripple-carry adder.
+pub fn kogge_stone_adder(bits: usize) -> Circuit {
+    let (mut circuit, a, b) = get_adder_inputs(bits);
+    let propagate: Vec<Wire> = (0..bits).map(|i: usize| circuit.xor(a[i], b[i])).collect();
+    let mut span_generate: Vec<Wire> = (0..bits).map(|i: usize| circuit.and(a[i], b[i])).collect();
+    let mut span_propagate: Vec<Wire> = propagate.clone();
+    let mut distance: usize = 1;
+
+    while distance < bits {
+        /*
+            combine the span ending at bit i with the span ending at bit i - distance:
+            G = G(i) | (P(i) & G(i - distance))
+            P = P(i) & P(i - distance)
+            after the last level G(i) is the carry going out of the bit i.
+         */
+        let mut next_generate: Vec<Wire> = span_generate.clone();
+        let mut next_propagate: Vec<Wire> = span_propagate.clone();
+
+        for i in distance..bits {
+            let carried: Wire = circuit.and(span_propagate[i], span_generate[i - distance]);
+
+            next_generate[i] = circuit.or(span_generate[i], carried);
+            // the propagate signal of a span reaching bit 0 is never read again
+            if i >= 2 * distance {
+                next_propagate[i] = circuit.and(span_propagate[i], span_propagate[i - distance]);
+            }
+        }
+        span_generate = next_generate;
+        span_propagate = next_propagate;
+        distance *= 2;
+    }
+    let mut carries: Vec<Option<Wire>> = vec![None];
+
+    carries.extend(span_generate.into_iter().map(Some));
+    add_sum_outputs(&mut circuit, &propagate, &carries);
+
+    circuit
+}
+
+/// Run an adder circuit built by one of the functions of this module
+/// and return the sum of a and b with the carry out
+/// # Panics
+/// If the circuit is not an adder of at most 64 bits
+pub fn run_adder(circuit: &Circuit, a: u64, b: u64) -> (u64, bool) {
+    let bits: usize = circuit.get_inputs().len() / 2;
+
+    if bits > 64 || circuit.get_outputs().len() != bits + 1 {
+        panic!("Invalid adder");
+    }
+    let inputs: Vec<bool> = (0..bits)
+        .map(|i: usize| a >> i & 1 != 0)
+        .chain((0..bits).map(|i: usize| b >> i & 1 != 0))
+        .collect();
+    let outputs: Vec<bool> = circuit.simulate(&inputs);
+    let sum: u64 = outputs[..bits]
+        .iter()
+        .enumerate()
+        .fold(0, |acc: u64, (i, bit): (usize, &bool)| acc | (*bit as u64) << i);
+
+    (sum, outputs[bits])
+}
+
+fn get_adder_inputs(bits: usize) -> (Circuit, Vec<Wire>, Vec<Wire>) {
+    if bits == 0 {
+        panic!("Invalid number of bits");
+    }
+    let mut circuit: Circuit = Circuit::new();
+    let a: Vec<Wire> = (0..bits).map(|_| circuit.add_input()).collect();
+    let b: Vec<Wire> = (0..bits).map(|_| circuit.add_input()).collect();
+
+    (circuit, a, b)
+}
+
+fn get_and_chain(circuit: &mut Circuit, wires: &[Wire], last: Wire) -> Wire {
+    wires
+        .iter()
+        .fold(last, |acc: Wire, wire: &Wire| circuit.and(*wire, acc))
+}
+
+/// Add the sum bits p(i) ^ c(i) and the carry out as the outputs,
+/// carries[i] is the carry going into the bit i (None for no carry)
+fn add_sum_outputs(circuit: &mut Circuit, propagate: &[Wire], carries: &[Option<Wire>]) {
+    for (i, p) in propagate.iter().enumerate() {
+        let sum: Wire = match carries[i] {
+            Some(carry) => circuit.xor(*p, carry),
+            None => *p,
+        };
+
+        circuit.add_output(sum);
+    }
+    if let Some(carry) = carries[propagate.len()] {
+        circuit.add_output(carry);
+    }
+}
@@ -1,3 +1,8 @@
+pub mod circuit;
+pub mod word;
+
+pub use word::Word;
+
 /// add two integers a and b using bitwise operators
 /// and return the result as u32
 /// # Examples
@@ -10,29 +15,117 @@
 /// assert_eq!(res, 6);
 /// ```
 pub fn adder(a: u32, b: u32) -> u32 {
-    let mut res: u32 = 0;
-    let mut carry: bool = false;
+    overflowing_adder(a, b).0
+}
+
+/// add two integers a and b of any width using bitwise operators
+/// and return the result with a boolean telling if the addition overflowed,
+/// like `overflowing_add` of the standard library
+/// # Examples
+/// ```
+/// use ex00::overflowing_adder;
+///
+/// assert_eq!((255, false), overflowing_adder(250u8, 5));
+/// assert_eq!((0, true), overflowing_adder(250u8, 6));
+/// assert_eq!((-128, true), overflowing_adder(127i8, 1));
+/// assert_eq!((-1, false), overflowing_adder(-3i64, 2));
+/// ```
+pub fn overflowing_adder<T: Word>(a: T, b: T) -> (T, bool) {
+    let (res, carry, msb_carry) = add_bits(a, b, false);
+
+    /*
+        an unsigned addition overflows when a carry goes out of the highest bit,
+        a signed addition overflows when the carry going into the sign bit is
+        different from the carry going out of it: 127 + 1 = -128 for an i8
+    */
+    if T::SIGNED {
+        (res, carry != msb_carry)
+    } else {
+        (res, carry)
+    }
+}
+
+/// add two integers a and b and an incoming carry using bitwise operators
+/// and return the result with the carry going out of the highest bit
+/// # Examples
+/// ```
+/// use ex00::carrying_adder;
+///
+/// assert_eq!((0, true), carrying_adder(u64::MAX, 0, true));
+/// assert_eq!((7, false), carrying_adder(3u8, 3, true));
+/// ```
+pub fn carrying_adder<T: Word>(a: T, b: T, carry: bool) -> (T, bool) {
+    let (res, carry, _) = add_bits(a, b, carry);
+
+    (res, carry)
+}
+
+/// subtract the integer b from the integer a using the two's complement
+/// a - b = a + !b + 1 and return the result with a boolean telling if the
+/// subtraction overflowed, like `overflowing_sub` of the standard library
+/// # Examples
+/// ```
+/// use ex00::overflowing_subtractor;
+///
+/// assert_eq!((2, false), overflowing_subtractor(5u32, 3));
+/// assert_eq!((u32::MAX, true), overflowing_subtractor(0u32, 1));
+/// assert_eq!((-8, false), overflowing_subtractor(-5i16, 3));
+/// assert_eq!((127, true), overflowing_subtractor(-128i8, 1));
+/// ```
+pub fn overflowing_subtractor<T: Word>(a: T, b: T) -> (T, bool) {
+    let (res, carry, msb_carry) = add_bits(a, b.invert(), true);
 
-    for i in 0..32 {
+    /*
+        for the unsigned integers the carry out of the highest bit is the
+        inverse of the borrow: 5 - 3 sets it while 3 - 5 doesn't
+    */
+    if T::SIGNED {
+        (res, carry != msb_carry)
+    } else {
+        (res, !carry)
+    }
+}
+
+/// get the two's complement negation of an integer: -a = !a + 1
+/// # Examples
+/// ```
+/// use ex00::negate;
+///
+/// assert_eq!(-42, negate(42i32));
+/// assert_eq!(u8::MAX, negate(1u8));
+/// ```
+pub fn negate<T: Word>(a: T) -> T {
+    carrying_adder(a.invert(), T::ZERO, true).0
+}
+
+fn add_bits<T: Word>(a: T, b: T, mut carry: bool) -> (T, bool, bool) {
+    let mut res: T = T::ZERO;
+    let mut msb_carry: bool = false;
+
+    for i in 0..T::BITS {
+        // keep the carry going into the highest bit to detect the signed overflows
+        if i == T::BITS - 1 {
+            msb_carry = carry;
+        }
         /*
             if this true means a and b have different bits at 
             index i, otherwise they have the same bits, either
             0 and 0 or 1 and 1
         */
-        if (a & (1 << i)) != (b & (1 << i)) {
+        if a.get_bit(i) != b.get_bit(i) {
             if !carry {
-                res |= 1 << i;
+                res = res.set_bit(i);
             }
         }
         else {
             if carry {
-                res |= 1 << i;
+                res = res.set_bit(i);
             }
-            carry = (a & (1 << i)) != 0;
+            carry = a.get_bit(i);
         }
     }
 
-    res
+    (res, carry, msb_carry)
 }
 
 #[cfg(test)]
@@ -69,5 +162,180 @@ mod tests {
         assert_eq!(1 + 1, adder(1, 1));
         assert_eq!(0 + 1, adder(0, 1));
         assert_eq!(4294967294 + 1, adder(4294967294, 1));
+        assert_eq!(0, adder(u32::MAX, 1));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn overflowing_add_tests() {
+        for a in u8::MIN..=u8::MAX {
+            for b in u8::MIN..=u8::MAX {
+                assert_eq!(a.overflowing_add(b), overflowing_adder(a, b));
+                assert_eq!((a as i8).overflowing_add(b as i8), overflowing_adder(a as i8, b as i8));
+            }
+        }
+        assert_eq!((u128::MAX, false), overflowing_adder(u128::MAX - 1, 1));
+        assert_eq!((0, true), overflowing_adder(u128::MAX, 1));
+        assert_eq!((i128::MIN, true), overflowing_adder(i128::MAX, 1));
+        assert_eq!((i64::MAX, true), overflowing_adder(i64::MIN, -1));
+        assert_eq!((1 << 40, false), overflowing_adder(1u64 << 39, 1 << 39));
+        assert_eq!((0, true), overflowing_adder(usize::MAX, 1));
+        assert_eq!((-1000, false), overflowing_adder(-1234isize, 234));
+    }
+
+    #[test]
+    fn overflowing_sub_tests() {
+        for a in u8::MIN..=u8::MAX {
+            for b in u8::MIN..=u8::MAX {
+                assert_eq!(a.overflowing_sub(b), overflowing_subtractor(a, b));
+                assert_eq!((a as i8).overflowing_sub(b as i8), overflowing_subtractor(a as i8, b as i8));
+            }
+        }
+        assert_eq!((u128::MAX, true), overflowing_subtractor(0u128, 1));
+        assert_eq!((i128::MAX, true), overflowing_subtractor(i128::MIN, 1));
+        assert_eq!((i32::MIN, false), overflowing_subtractor(i32::MIN + 10, 10));
+        assert_eq!((123_456_789, false), overflowing_subtractor(123_456_789u64 * 2, 123_456_789));
+    }
+
+    #[test]
+    fn carrying_add_tests() {
+        assert_eq!((0, true), carrying_adder(u16::MAX, 0, true));
+        assert_eq!((u16::MAX, false), carrying_adder(u16::MAX, 0, false));
+        assert_eq!((1, true), carrying_adder(u32::MAX, 1, true));
+        assert_eq!((-1, true), carrying_adder(-1i8, -1, true));
+    }
+
+    #[test]
+    fn negate_tests() {
+        assert_eq!(0, negate(0u32));
+        assert_eq!(u32::MAX, negate(1u32));
+        assert_eq!(-5, negate(5i16));
+        assert_eq!(i8::MIN, negate(i8::MIN));
+        assert_eq!(i128::MAX, negate(i128::MIN + 1));
+    }
+
+    #[test]
+    fn shift_right_tests() {
+        assert_eq!(0x7F, (-1i8).shift_right(1));
+        assert_eq!(1, i64::MIN.shift_right(63));
+        assert_eq!(-1, (-1i32).shift_right(0));
+        assert_eq!(0x0F, 0xF0u8.shift_right(4));
+    }
+
+    /// A xorshift generator to get reproducible random inputs
+    fn xorshift(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    fn get_adders(bits: usize) -> Vec<circuit::Circuit> {
+        vec![
+            circuit::ripple_carry_adder(bits),
+            circuit::carry_lookahead_adder(bits),
+            circuit::kogge_stone_adder(bits),
+        ]
+    }
+
+    #[test]
+    fn circuit_adders_exhaustive_tests() {
+        for bits in [1, 3, 5, 8] {
+            for circuit in get_adders(bits) {
+                for a in 0..(1u64 << bits) {
+                    for b in 0..(1u64 << bits) {
+                        let sum: u64 = a + b;
+
+                        assert_eq!((sum & ((1 << bits) - 1), sum >> bits == 1), circuit::run_adder(&circuit, a, b));
+                    }
+                }
+            }
+        }
+        for circuit in get_adders(8) {
+            for a in u8::MIN..=u8::MAX {
+                for b in u8::MIN..=u8::MAX {
+                    let (sum, carry) = circuit::run_adder(&circuit, a as u64, b as u64);
+
+                    assert_eq!(overflowing_adder(a, b), (sum as u8, carry));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn circuit_adders_random_tests() {
+        let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+
+        for circuit in get_adders(32) {
+            for _ in 0..2000 {
+                let a: u32 = xorshift(&mut state) as u32;
+                let b: u32 = xorshift(&mut state) as u32;
+                let (sum, carry) = circuit::run_adder(&circuit, a as u64, b as u64);
+
+                assert_eq!(adder(a, b), sum as u32);
+                assert_eq!(overflowing_adder(a, b).1, carry);
+            }
+            // the longest carry chain
+            assert_eq!((0, true), circuit::run_adder(&circuit, u32::MAX as u64, 1));
+        }
+        for circuit in get_adders(64) {
+            for _ in 0..500 {
+                let a: u64 = xorshift(&mut state);
+                let b: u64 = xorshift(&mut state);
+
+                assert_eq!(overflowing_adder(a, b), circuit::run_adder(&circuit, a, b));
+            }
+        }
+    }
+
+    #[test]
+    fn circuit_metrics_tests() {
+        let ripple = circuit::ripple_carry_adder(32);
+        let lookahead = circuit::carry_lookahead_adder(32);
+        let kogge_stone = circuit::kogge_stone_adder(32);
+
+        // a half adder for the first bit, then 5 gates by full adder
+        assert_eq!(2 + 31 * 5, ripple.gate_count());
+        // each full adder adds an AND and an OR on the carry chain
+        assert_eq!(2 * 32 - 1, ripple.depth());
+        // each of the 5 levels of the prefix tree adds an AND and an OR on the critical path
+        assert_eq!(1 + 5 * 2, kogge_stone.depth());
+        assert!(kogge_stone.depth() < lookahead.depth());
+        assert!(lookahead.depth() < ripple.depth());
+        assert!(ripple.gate_count() < lookahead.gate_count());
+        assert!(ripple.gate_count() < kogge_stone.gate_count());
+        // every wire of a full adder feeds a XOR and an AND, the carry out only the next full adder
+        assert_eq!(2, ripple.fan_out(ripple.get_inputs()[0]));
+        assert_eq!(2, ripple.max_fan_out());
+        assert_eq!(1, ripple.fan_out(*ripple.get_outputs().last().unwrap()));
+    }
+
+    #[test]
+    fn circuit_simulation_tests() {
+        let mut state: u64 = 88172645463325252;
+
+        for circuit in get_adders(16) {
+            for _ in 0..100 {
+                let inputs: Vec<bool> = (0..32).map(|_| xorshift(&mut state) & 1 != 0).collect();
+                let mut simulation = circuit.start_simulation(&inputs);
+                let cycles: usize = simulation.settle();
+
+                assert!(cycles <= circuit.depth());
+                assert_eq!(circuit.simulate(&inputs), simulation.get_outputs());
+            }
+        }
+        // the carry out of 0xFFFF + 1 needs the whole chain of the ripple-carry adder
+        let ripple = circuit::ripple_carry_adder(16);
+        let mut inputs: Vec<bool> = vec![true; 16];
+        inputs.push(true);
+        inputs.extend(vec![false; 15]);
+        let mut simulation = ripple.start_simulation(&inputs);
+        assert_eq!(ripple.depth(), simulation.settle());
+        assert_eq!(ripple.depth() + 1, simulation.get_cycles());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid number of inputs")]
+    fn circuit_invalid_inputs_tests() {
+        circuit::ripple_carry_adder(4).simulate(&[true, false]);
+    }
+}
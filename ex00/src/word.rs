@@ -0,0 +1,80 @@
+use std::fmt::Debug;
+
+/// An integer of a fixed width handled bit by bit, the signed integers
+/// use the two's complement representation
+pub trait Word: Copy + PartialEq + Debug {
+    /// The number of bits of the word
+    const BITS: u32;
+    /// The word with all the bits unset
+    const ZERO: Self;
+    /// A boolean to check if the word is a signed integer
+    const SIGNED: bool;
+
+    /// Check if the bit at index i is set
+    fn get_bit(self, i: u32) -> bool;
+
+    /// Get the word with the bit at index i set
+    fn set_bit(self, i: u32) -> Self;
+
+    /// Get the word with all its bits inverted
+    fn invert(self) -> Self;
+
+    /// Shift the bits of the word by n to the left, n should be less than BITS
+    fn shift_left(self, n: u32) -> Self;
+
+    /// Shift the bits of the word by n to the right filling with zeros,
+    /// n should be less than BITS
+    fn shift_right(self, n: u32) -> Self;
+}
+
+macro_rules! impl_word {
+    ($($t:ty => $signed:expr),* $(,)?) => {
+        $(
+            impl Word for $t {
+                const BITS: u32 = <$t>::BITS;
+                const ZERO: Self = 0;
+                const SIGNED: bool = $signed;
+
+                fn get_bit(self, i: u32) -> bool {
+                    (self >> i) & 1 != 0
+                }
+
+                fn set_bit(self, i: u32) -> Self {
+                    self | (1 << i)
+                }
+
+                fn invert(self) -> Self {
+                    !self
+                }
+
+                fn shift_left(self, n: u32) -> Self {
+                    self << n
+                }
+
+                fn shift_right(self, n: u32) -> Self {
+                    // clear the copies of the sign bit brought by the arithmetic shift
+                    if n == 0 {
+                        self
+                    } else {
+                        (self >> n) & !(!0 << (Self::BITS - n))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_word!(
+    u8 => false,
+    u16 => false,
+    u32 => false,
+    u64 => false,
+    u128 => false,
+    usize => false,
+    i8 => true,
+    i16 => true,
+    i32 => true,
+    i64 => true,
+    i128 => true,
+    isize => true,
+);
@@ -1,5 +1,18 @@
+pub mod word;
+
+pub use word::Word;
+
+/// The number of primitive operations used by an arithmetic function
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct OpCount {
+    /// The number of calls to the adder, a subtraction is an addition of the inverted operand
+    pub additions: u32,
+    /// The number of calls to the multiplier
+    pub multiplications: u32,
+}
+
 /// add two integers a and b using bitwise operators
-/// and return the result as a u32
+/// and store the result in a, the result wraps around on overflow
 /// # Examples
 /// ```
 /// use ex01::adder;
@@ -9,30 +22,8 @@
 /// adder(&mut a, b);
 /// assert_eq!(a, 6);
 /// ```
-fn adder(a: &mut u32, b: u32) {
-    let mut res: u32 = 0;
-    let mut carry: bool = false;
-
-    for i in 0..32 {
-        /*
-            if this true means a and b have different bits at 
-            index i, otherwise they have the same bits, either
-            0 and 0 or 1 and 1
-        */
-        if (*a & (1 << i)) != (b & (1 << i)) {
-            if !carry {
-                res |= 1 << i;
-            }
-        }
-        else {
-            if carry {
-                res |= 1 << i;
-            }
-            carry = (*a & (1 << i)) != 0;
-        }
-    }
-
-    *a = res
+pub fn adder<T: Word>(a: &mut T, b: T) {
+    *a = add_bits(*a, b, false).0
 }
 
 /// multiply two integers a and b using bitwise operators
@@ -48,20 +39,389 @@ fn adder(a: &mut u32, b: u32) {
 /// assert_eq!(res, 10);
 /// ```
 pub fn multiplier(a: u32, b: u32) -> u32 {
-    let mut min = a.min(b);
-    let max = a.max(b);
-    let mut res = max;
+    wrapping_multiplier(a, b)
+}
+
+/// multiply two integers a and b and return the full 64 bits product,
+/// so the result never overflows
+/// # Examples
+/// ```
+/// use ex01::full_multiplier;
+///
+/// assert_eq!(18446744065119617025, full_multiplier(u32::MAX, u32::MAX));
+/// ```
+pub fn full_multiplier(a: u32, b: u32) -> u64 {
+    let (low, high) = widening_multiplier(a, b);
 
-    if min == 0 {
-        return 0;
+    (high as u64) << 32 | low as u64
+}
+
+/// multiply two integers a and b of any width by calling the adder function,
+/// the result wraps around like `wrapping_mul` of the standard library
+/// # Examples
+/// ```
+/// use ex01::wrapping_multiplier;
+///
+/// assert_eq!(200, wrapping_multiplier(20u8, 10));
+/// assert_eq!(44, wrapping_multiplier(20u8, 15));
+/// assert_eq!(-21, wrapping_multiplier(7i64, -3));
+/// ```
+pub fn wrapping_multiplier<T: Word>(a: T, b: T) -> T {
+    widening_multiplier(a, b).0
+}
+
+/// multiply two integers a and b of any width and return the wrapped result
+/// with a boolean to check if the product overflowed
+/// # Examples
+/// ```
+/// use ex01::overflowing_multiplier;
+///
+/// assert_eq!((200, false), overflowing_multiplier(20u8, 10));
+/// assert_eq!((44, true), overflowing_multiplier(20u8, 15));
+/// assert_eq!((-128, false), overflowing_multiplier(-64i8, 2));
+/// assert_eq!((-128, true), overflowing_multiplier(64i8, 2));
+/// ```
+pub fn overflowing_multiplier<T: Word>(a: T, b: T) -> (T, bool) {
+    let (low, high) = widening_multiplier(a, b);
+    /*
+        the product fits in a word if the high word only holds the copies of its sign:
+        all the bits unset for the unsigned integers, the sign of the low word otherwise
+    */
+    let sign: T = if T::SIGNED && low.get_bit(T::BITS - 1) { T::ZERO.invert() } else { T::ZERO };
+
+    (low, high != sign)
+}
+
+/// multiply two integers a and b of any width and return the full product
+/// as a pair (low word, high word), the high word of a signed product is signed
+/// # Examples
+/// ```
+/// use ex01::widening_multiplier;
+///
+/// assert_eq!((0x20, 0x03), widening_multiplier(100u8, 8));
+/// assert_eq!((-2, -1), widening_multiplier(-2i8, 1));
+/// ```
+pub fn widening_multiplier<T: Word>(a: T, b: T) -> (T, T) {
+    let (low, high, _) = shift_and_add(a, b);
+
+    (low, high)
+}
+
+/// multiply the bits of a and b as unsigned integers into a double word and
+/// fix the high word of the signed integers, return the number of additions as well
+fn shift_and_add<T: Word>(a: T, b: T) -> (T, T, u32) {
+    /*
+        write b as a sum of powers of two, then a * b is the sum of a shifted to
+        the left by the index of each bit set in b:
+            a * 0b101 = (a << 2) + (a << 0)
+        so there is at most one addition by bit of b instead of b additions.
+        the shifted a is kept in a double word (low, high) so no bit is lost.
+    */
+    let mut low: T = T::ZERO;
+    let mut high: T = T::ZERO;
+    let mut additions: u32 = 0;
+
+    for i in 0..T::BITS {
+        if b.get_bit(i) {
+            let shifted_high: T = if i == 0 { T::ZERO } else { a.shift_right(T::BITS - i) };
+            let (new_low, carry) = add_bits(low, a.shift_left(i), false);
+
+            low = new_low;
+            high = add_bits(high, shifted_high, carry).0;
+            additions += 1;
+        }
     }
-    min -= 1;
-    while min != 0 {
-       adder(&mut res, max);
-       min -= 1;
+    /*
+        in two's complement a negative a is read as a + 2^BITS when its bits are
+        multiplied as unsigned, so its product is too big by b * 2^BITS, which
+        only changes the high word: subtract b from it (and a if b is negative)
+    */
+    if T::SIGNED {
+        if a.get_bit(T::BITS - 1) {
+            high = add_bits(high, b.invert(), true).0;
+            additions += 1;
+        }
+        if b.get_bit(T::BITS - 1) {
+            high = add_bits(high, a.invert(), true).0;
+            additions += 1;
+        }
+    }
+
+    (low, high, additions)
+}
+
+/// divide a by b using the restoring division and return the pair (quotient, remainder),
+/// the quotient is truncated toward zero and the remainder has the sign of a
+/// like the `/` and `%` operators, the division of the minimum signed integer by -1 wraps around
+/// # Panics
+/// If b is zero
+/// # Examples
+/// ```
+/// use ex01::divider;
+///
+/// assert_eq!((14, 2), divider(100u32, 7));
+/// assert_eq!((-14, -2), divider(-100i32, 7));
+/// ```
+pub fn divider<T: Word>(a: T, b: T) -> (T, T) {
+    restoring_divider(a, b, &mut OpCount::default())
+}
+
+/// divide a by b like the divider function, but return None if b is zero
+/// # Examples
+/// ```
+/// use ex01::checked_divider;
+///
+/// assert_eq!(Some((3, 1)), checked_divider(10u8, 3));
+/// assert_eq!(None, checked_divider(10u8, 0));
+/// ```
+pub fn checked_divider<T: Word>(a: T, b: T) -> Option<(T, T)> {
+    if b == T::ZERO {
+        return None;
     }
 
-    res
+    Some(divider(a, b))
+}
+
+/// get the remainder of the division of a by b, which has the sign of a
+/// # Panics
+/// If b is zero
+/// # Examples
+/// ```
+/// use ex01::modulo;
+///
+/// assert_eq!(2, modulo(100u64, 7));
+/// assert_eq!(-2, modulo(-100i64, 7));
+/// ```
+pub fn modulo<T: Word>(a: T, b: T) -> T {
+    divider(a, b).1
+}
+
+/// divide a by b using the restoring division and record the operations in count:
+/// the divisor is subtracted from the partial remainder at each bit,
+/// and added back when the result is negative
+/// # Panics
+/// If b is zero
+/// # Examples
+/// ```
+/// use ex01::{restoring_divider, OpCount};
+/// let mut count: OpCount = OpCount::default();
+///
+/// assert_eq!((4, 3), restoring_divider(43u8, 10, &mut count));
+/// assert_eq!(8 + 7, count.additions);
+/// ```
+pub fn restoring_divider<T: Word>(a: T, b: T, count: &mut OpCount) -> (T, T) {
+    divide_signed(a, b, count, |a: T, b: T, count: &mut OpCount| {
+        /*
+            for each bit of a from the most significant one, shift it into the
+            partial remainder and try to subtract b:
+            - if the remainder is still positive, the bit of the quotient is 1
+            - otherwise the bit is 0 and b is added back to restore the remainder.
+            the remainder holds BITS + 1 bits while shifted, so the bit pushed
+            out of the word makes it bigger than b.
+        */
+        let mut quotient: T = T::ZERO;
+        let mut remainder: T = T::ZERO;
+
+        for i in (0..T::BITS).rev() {
+            let overflow: bool = remainder.get_bit(T::BITS - 1);
+
+            remainder = remainder.shift_left(1);
+            if a.get_bit(i) {
+                remainder = remainder.set_bit(0);
+            }
+            let (difference, no_borrow) = add_bits(remainder, b.invert(), true);
+
+            remainder = difference;
+            count.additions += 1;
+            if overflow || no_borrow {
+                quotient = quotient.set_bit(i);
+            } else {
+                remainder = add_bits(remainder, b, false).0;
+                count.additions += 1;
+            }
+        }
+
+        (quotient, remainder)
+    })
+}
+
+/// divide a by b using the non-restoring division and record the operations in count:
+/// a negative partial remainder is not restored, b is added to it at the next bit instead
+/// of being subtracted, so there is a single addition by bit
+/// # Panics
+/// If b is zero
+/// # Examples
+/// ```
+/// use ex01::{non_restoring_divider, OpCount};
+/// let mut count: OpCount = OpCount::default();
+///
+/// assert_eq!((4, 3), non_restoring_divider(43u8, 10, &mut count));
+/// assert_eq!(8 + 1, count.additions);
+/// ```
+pub fn non_restoring_divider<T: Word>(a: T, b: T, count: &mut OpCount) -> (T, T) {
+    divide_signed(a, b, count, |a: T, b: T, count: &mut OpCount| {
+        /*
+            the partial remainder is a signed integer of BITS + 1 bits, the word
+            holds its low bits and negative holds its sign bit, which is the bit
+            shifted out of the word plus the carry of the addition:
+            - when it is positive, shift it and subtract b
+            - when it is negative, shift it and add b
+            the bit of the quotient is 1 when the new remainder is positive.
+            at the end, a negative remainder is restored once by adding b.
+        */
+        let mut quotient: T = T::ZERO;
+        let mut remainder: T = T::ZERO;
+        let mut negative: bool = false;
+
+        for i in (0..T::BITS).rev() {
+            let sign: bool = remainder.get_bit(T::BITS - 1);
+
+            remainder = remainder.shift_left(1);
+            if a.get_bit(i) {
+                remainder = remainder.set_bit(0);
+            }
+            // the sign bit of b is 0, and 1 once inverted to subtract it
+            let (sum, carry) = if negative {
+                add_bits(remainder, b, false)
+            } else {
+                add_bits(remainder, b.invert(), true)
+            };
+
+            remainder = sum;
+            negative = if negative { sign != carry } else { sign == carry };
+            count.additions += 1;
+            if !negative {
+                quotient = quotient.set_bit(i);
+            }
+        }
+        if negative {
+            remainder = add_bits(remainder, b, false).0;
+            count.additions += 1;
+        }
+
+        (quotient, remainder)
+    })
+}
+
+/// raise base to the power exp by calling the multiplier function,
+/// the result wraps around on overflow
+/// # Examples
+/// ```
+/// use ex01::power;
+///
+/// assert_eq!(1024, power(2u32, 10));
+/// assert_eq!(-27, power(-3i8, 3));
+/// assert_eq!(1, power(0u8, 0));
+/// ```
+pub fn power<T: Word>(base: T, exp: u32) -> T {
+    power_with_count(base, exp, &mut OpCount::default())
+}
+
+/// raise base to the power exp using the square-and-multiply method
+/// and record the operations in count
+/// # Examples
+/// ```
+/// use ex01::{power_with_count, OpCount};
+/// let mut count: OpCount = OpCount::default();
+///
+/// assert_eq!(3486784401, power_with_count(3u64, 20, &mut count));
+/// assert_eq!(5, count.multiplications);
+/// ```
+pub fn power_with_count<T: Word>(base: T, exp: u32, count: &mut OpCount) -> T {
+    /*
+        read exp from its most significant bit, for each bit square the result,
+        and multiply it by base if the bit is set:
+            3 ^ 0b101 = ((3) ^ 2) ^ 2 * 3
+        so there are at most two multiplications by bit of exp instead of exp ones.
+    */
+    let mut res: Option<T> = None;
+
+    for i in (0..u32::BITS).rev() {
+        if let Some(value) = res {
+            res = Some(counted_multiplier(value, value, count));
+        }
+        if exp.get_bit(i) {
+            res = Some(match res {
+                Some(value) => counted_multiplier(value, base, count),
+                None => base,
+            });
+        }
+    }
+
+    // base ^ 0 is 1 even for a zero base
+    res.unwrap_or_else(|| T::ZERO.set_bit(0))
+}
+
+fn counted_multiplier<T: Word>(a: T, b: T, count: &mut OpCount) -> T {
+    let (low, _, additions) = shift_and_add(a, b);
+
+    count.additions += additions;
+    count.multiplications += 1;
+
+    low
+}
+
+/// divide the absolute values of a and b with the given unsigned division,
+/// then give the quotient and the remainder their signs
+fn divide_signed<T: Word>(
+    a: T,
+    b: T,
+    count: &mut OpCount,
+    divide: impl Fn(T, T, &mut OpCount) -> (T, T),
+) -> (T, T) {
+    if b == T::ZERO {
+        panic!("Division by zero");
+    }
+    let a_negative: bool = T::SIGNED && a.get_bit(T::BITS - 1);
+    let b_negative: bool = T::SIGNED && b.get_bit(T::BITS - 1);
+    /*
+        the absolute value of the minimum signed integer does not fit in the word,
+        but its bits read as an unsigned integer are right, e.g. -128i8 is 0b1000_0000
+    */
+    let (quotient, remainder) = divide(
+        negate_if(a, a_negative, count),
+        negate_if(b, b_negative, count),
+        count,
+    );
+
+    (
+        negate_if(quotient, a_negative != b_negative, count),
+        negate_if(remainder, a_negative, count),
+    )
+}
+
+fn negate_if<T: Word>(a: T, negate: bool, count: &mut OpCount) -> T {
+    if !negate {
+        return a;
+    }
+    count.additions += 1;
+
+    add_bits(a.invert(), T::ZERO, true).0
+}
+
+fn add_bits<T: Word>(a: T, b: T, mut carry: bool) -> (T, bool) {
+    let mut res: T = T::ZERO;
+
+    for i in 0..T::BITS {
+        /*
+            if this true means a and b have different bits at 
+            index i, otherwise they have the same bits, either
+            0 and 0 or 1 and 1
+        */
+        if a.get_bit(i) != b.get_bit(i) {
+            if !carry {
+                res = res.set_bit(i);
+            }
+        }
+        else {
+            if carry {
+                res = res.set_bit(i);
+            }
+            carry = a.get_bit(i);
+        }
+    }
+
+    (res, carry)
 }
 
 
@@ -95,4 +455,159 @@ mod tests {
         assert_eq!(1 * 0, multiplier(1, 0));
         assert_eq!(0 * 0, multiplier(0, 0));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn generic_adder_test() {
+        let mut a: u8 = 250;
+        adder(&mut a, 10);
+        assert_eq!(4, a);
+
+        let mut b: i16 = -300;
+        adder(&mut b, 100);
+        assert_eq!(-200, b);
+
+        let mut c: u128 = u64::MAX as u128;
+        adder(&mut c, 1);
+        assert_eq!(1 << 64, c);
+    }
+
+    #[test]
+    fn generic_multiplier_test() {
+        for a in u8::MIN..=u8::MAX {
+            for b in [0, 1, 2, 3, 7, 16, 100, 255] {
+                assert_eq!(a.wrapping_mul(b), wrapping_multiplier(a, b));
+                assert_eq!((a as i8).wrapping_mul(b as i8), wrapping_multiplier(a as i8, b as i8));
+            }
+        }
+        assert_eq!(-12 * 11, wrapping_multiplier(-12i32, 11));
+        assert_eq!(1_000_000_007 * 3, wrapping_multiplier(1_000_000_007u128, 3));
+        assert_eq!(-5 * 250, wrapping_multiplier(250i64, -5));
+    }
+
+    #[test]
+    fn full_multiplier_test() {
+        assert_eq!(44444 * 66666, full_multiplier(44444, 66666));
+        assert_eq!(u32::MAX as u64 * u32::MAX as u64, full_multiplier(u32::MAX, u32::MAX));
+        assert_eq!(1 << 32, full_multiplier(1 << 16, 1 << 16));
+        assert_eq!(0, full_multiplier(u32::MAX, 0));
+        assert_eq!(u32::MAX.wrapping_mul(3), multiplier(u32::MAX, 3));
+    }
+
+    #[test]
+    fn widening_multiplier_test() {
+        for a in u8::MIN..=u8::MAX {
+            for b in u8::MIN..=u8::MAX {
+                let product: u16 = a as u16 * b as u16;
+                let signed_product: i16 = a as i8 as i16 * b as i8 as i16;
+
+                assert_eq!((product as u8, (product >> 8) as u8), widening_multiplier(a, b));
+                assert_eq!(
+                    (signed_product as i8, (signed_product >> 8) as i8),
+                    widening_multiplier(a as i8, b as i8)
+                );
+            }
+        }
+        let (low, high) = widening_multiplier(u128::MAX, 2);
+        assert_eq!((u128::MAX - 1, 1), (low, high));
+        let (low, high) = widening_multiplier(i64::MIN, -1);
+        assert_eq!(-(i64::MIN as i128), (high as i128) << 64 | low as u64 as i128);
+    }
+
+    #[test]
+    fn overflowing_multiplier_test() {
+        for a in u8::MIN..=u8::MAX {
+            for b in u8::MIN..=u8::MAX {
+                assert_eq!(a.overflowing_mul(b), overflowing_multiplier(a, b));
+                assert_eq!((a as i8).overflowing_mul(b as i8), overflowing_multiplier(a as i8, b as i8));
+            }
+        }
+        assert_eq!(i32::MIN.overflowing_mul(-1), overflowing_multiplier(i32::MIN, -1));
+        assert_eq!(u64::MAX.overflowing_mul(u64::MAX), overflowing_multiplier(u64::MAX, u64::MAX));
+    }
+
+    #[test]
+    fn additions_count_test() {
+        // one addition by bit set in b, whatever the value of a
+        assert_eq!(0, shift_and_add(u32::MAX, 0).2);
+        assert_eq!(1, shift_and_add(u32::MAX, 1 << 31).2);
+        assert_eq!(8, shift_and_add(44444u32, 0xff).2);
+        assert_eq!(32, shift_and_add(u32::MAX, u32::MAX).2);
+        assert_eq!(66666u32.count_ones(), shift_and_add(44444u32, 66666).2);
+        // the signed integers need at most two more additions to fix the high word
+        assert_eq!(34, shift_and_add(-1i32, -1).2);
+    }
+
+    #[test]
+    fn divider_test() {
+        for a in u8::MIN..=u8::MAX {
+            for b in 1..=u8::MAX {
+                assert_eq!((a / b, a % b), divider(a, b));
+                assert_eq!((a / b, a % b), non_restoring_divider(a, b, &mut OpCount::default()));
+            }
+        }
+        for a in i8::MIN..=i8::MAX {
+            for b in (i8::MIN..=i8::MAX).filter(|b: &i8| *b != 0) {
+                let expected: (i8, i8) = (a.wrapping_div(b), a.wrapping_rem(b));
+
+                assert_eq!(expected, divider(a, b));
+                assert_eq!(expected, non_restoring_divider(a, b, &mut OpCount::default()));
+            }
+        }
+        assert_eq!((u64::MAX / 3, 0), divider(u64::MAX, 3));
+        assert_eq!((1, 1), divider(u128::MAX, u128::MAX - 1));
+        assert_eq!((1, 1), non_restoring_divider(u32::MAX, u32::MAX - 1, &mut OpCount::default()));
+        assert_eq!(-7, modulo(-7i32, 100));
+        assert_eq!(None, checked_divider(-7i32, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn divider_by_zero_test() {
+        divider(1u32, 0);
+    }
+
+    #[test]
+    fn divider_count_test() {
+        let mut restoring: OpCount = OpCount::default();
+        let mut non_restoring: OpCount = OpCount::default();
+
+        // 0b1000_0000 / 0b1111_1111 never fits, every bit is restored
+        divider_counts(128u8, 255, &mut restoring, &mut non_restoring);
+        assert_eq!(16, restoring.additions);
+        assert_eq!(9, non_restoring.additions);
+        // the non-restoring division never needs more than one addition by bit and a final one
+        for b in 1..=u16::MAX {
+            let mut restoring: OpCount = OpCount::default();
+            let mut non_restoring: OpCount = OpCount::default();
+
+            divider_counts(u16::MAX, b, &mut restoring, &mut non_restoring);
+            assert!(non_restoring.additions <= 17);
+            assert!(non_restoring.additions <= restoring.additions + 1);
+            assert_eq!(0, restoring.multiplications + non_restoring.multiplications);
+        }
+    }
+
+    fn divider_counts<T: Word>(a: T, b: T, restoring: &mut OpCount, non_restoring: &mut OpCount) {
+        assert_eq!(restoring_divider(a, b, restoring), non_restoring_divider(a, b, non_restoring));
+    }
+
+    #[test]
+    fn power_test() {
+        for base in u8::MIN..=u8::MAX {
+            for exp in 0..20 {
+                assert_eq!(base.wrapping_pow(exp), power(base, exp));
+                assert_eq!((base as i8).wrapping_pow(exp), power(base as i8, exp));
+            }
+        }
+        assert_eq!(3u128.pow(80), power(3u128, 80));
+        assert_eq!(7u64.wrapping_pow(u32::MAX), power(7u64, u32::MAX));
+
+        let mut count: OpCount = OpCount::default();
+        // 2 ^ 0b1_0000_0000 needs 8 squares and no multiplication by the base
+        assert_eq!(0, power_with_count(2u32, 256, &mut count));
+        assert_eq!(8, count.multiplications);
+        let mut count: OpCount = OpCount::default();
+        power_with_count(3u64, u32::MAX, &mut count);
+        assert_eq!(62, count.multiplications);
+    }
+}
@@ -0,0 +1,129 @@
+/// Get a cyclic balanced Gray code of the given number of bits, where each bit
+/// flips about the same number of times over the whole cycle (the counts differ by at most 2),
+/// while the reflected Gray code flips the lowest bit half of the time
+/// # Arguments
+/// * `bits` - The number of bits, between 1 and 5
+/// # Panics
+/// If the number of bits is not between 1 and 5, the search gets too slow above
+/// # Examples
+/// ```
+/// use ex02::balanced::{balanced_gray_code, transition_counts};
+///
+/// assert_eq!(vec![0, 1, 3, 2, 6, 7, 5, 4], balanced_gray_code(3));
+/// assert_eq!(vec![4, 4, 4, 4], transition_counts(&balanced_gray_code(4)));
+/// ```
+pub fn balanced_gray_code(bits: u32) -> Vec<u32> {
+    if bits == 0 || bits > 5 {
+        panic!("Invalid number of bits");
+    }
+    let total: u32 = 1 << bits;
+    /*
+        the counts of a cyclic code are even, so each bit should flip either
+        min_count times, the highest even number of times below total / bits,
+        or min_count + 2 times when total / bits is not even
+     */
+    let min_count: u32 = total / bits - (total / bits) % 2;
+    let max_count: u32 = if min_count * bits < total { min_count + 2 } else { min_count };
+    let mut search: Search = Search {
+        bits,
+        min_count,
+        max_count,
+        path: vec![0],
+        visited: vec![false; total as usize],
+        counts: vec![0; bits as usize],
+    };
+
+    search.visited[0] = true;
+    if !search.run() {
+        panic!("No balanced Gray code");
+    }
+
+    search.path
+}
+
+/// Get the number of times each bit flips in the cyclic sequence of codes,
+/// the transition from the last code to the first one is counted as well
+pub fn transition_counts(codes: &[u32]) -> Vec<u32> {
+    let bits: u32 = codes.iter().map(|code: &u32| u32::BITS - code.leading_zeros()).max().unwrap_or(0);
+    let mut counts: Vec<u32> = vec![0; bits as usize];
+
+    for (i, code) in codes.iter().enumerate() {
+        let flipped: u32 = code ^ codes[(i + 1) % codes.len()];
+
+        for (bit, count) in counts.iter_mut().enumerate() {
+            if flipped & (1 << bit) != 0 {
+                *count += 1;
+            }
+        }
+    }
+
+    counts
+}
+
+struct Search {
+    bits: u32,
+    min_count: u32,
+    max_count: u32,
+    path: Vec<u32>,
+    visited: Vec<bool>,
+    counts: Vec<u32>,
+}
+
+impl Search {
+    fn run(&mut self) -> bool {
+        /*
+            Depth-first search of a Hamiltonian cycle of the hypercube: from the last
+            code of the path, try to flip each bit which hasn't reached max_count yet.
+            A path is dropped when the remaining transitions can't bring every bit
+            to min_count anymore.
+         */
+        let total: usize = self.visited.len();
+        let curr: u32 = *self.path.last().unwrap();
+
+        if self.path.len() == total {
+            // the last code should be one flip away from 0 to close the cycle
+            if curr.count_ones() != 1 {
+                return false;
+            }
+            let bit: usize = curr.trailing_zeros() as usize;
+
+            return self.counts.iter().enumerate().all(|(i, count)| {
+                let count: u32 = count + (i == bit) as u32;
+
+                count >= self.min_count && count <= self.max_count
+            });
+        }
+        let remaining: u32 = (total - self.path.len() + 1) as u32;
+        let missing: u32 = self
+            .counts
+            .iter()
+            .map(|count: &u32| self.min_count.saturating_sub(*count))
+            .sum();
+
+        if missing > remaining {
+            return false;
+        }
+        for bit in 0..self.bits {
+            let next: u32 = curr ^ (1 << bit);
+
+            // all the bits are symmetric for the first flip
+            if self.visited[next as usize]
+                || self.counts[bit as usize] == self.max_count
+                || (self.path.len() == 1 && bit != 0)
+            {
+                continue;
+            }
+            self.visited[next as usize] = true;
+            self.counts[bit as usize] += 1;
+            self.path.push(next);
+            if self.run() {
+                return true;
+            }
+            self.path.pop();
+            self.counts[bit as usize] -= 1;
+            self.visited[next as usize] = false;
+        }
+
+        false
+    }
+}
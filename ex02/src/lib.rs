@@ -1,3 +1,9 @@
+pub mod balanced;
+pub mod nary;
+pub mod word;
+
+pub use word::Word;
+
 /// calculate the gray code of a given number.
 /// 
 /// # Examples
@@ -13,6 +19,85 @@ pub fn gray_code(n: u32) -> u32 {
     n ^ (n >> 1)
 }
 
+/// calculate the number from its gray code.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use ex02::gray_decode;
+/// 
+/// assert_eq!(0, gray_decode(0));
+/// assert_eq!(2, gray_decode(3));
+/// assert_eq!(5, gray_decode(7));
+/// ``` 
+pub fn gray_decode(g: u32) -> u32 {
+    g.gray_decode()
+}
+
+/// calculate the gray code following g in the sequence without decoding it.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use ex02::{gray_code, gray_increment};
+/// 
+/// assert_eq!(gray_code(6), gray_increment(gray_code(5)));
+/// assert_eq!(0, gray_increment(gray_code(u32::MAX)));
+/// ``` 
+pub fn gray_increment(g: u32) -> u32 {
+    g.gray_increment()
+}
+
+/// An iterator over the reflected gray codes of a given number of bits,
+/// from 0 to the code with only the highest bit set
+#[derive(Debug, Clone)]
+pub struct GraySequence<T: Word> {
+    next: Option<T>,
+    last: T,
+}
+
+impl<T: Word> Iterator for GraySequence<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let curr: T = self.next?;
+
+        self.next = if curr == self.last {
+            None
+        } else {
+            Some(curr.gray_increment())
+        };
+        Some(curr)
+    }
+}
+
+/// get the sequence of the 2 ^ bits gray codes of a given number of bits,
+/// two consecutive codes only differ by one bit.
+/// 
+/// # Panics
+/// 
+/// If the number of bits is more than the width of T
+/// 
+/// # Examples
+/// 
+/// ```
+/// use ex02::gray_sequence;
+/// 
+/// let codes: Vec<u8> = gray_sequence(3).collect();
+/// 
+/// assert_eq!(vec![0, 1, 3, 2, 6, 7, 5, 4], codes);
+/// ``` 
+pub fn gray_sequence<T: Word>(bits: u32) -> GraySequence<T> {
+    if bits > T::BITS {
+        panic!("Invalid number of bits");
+    }
+
+    GraySequence {
+        next: Some(T::ZERO),
+        last: if bits == 0 { T::ZERO } else { T::ZERO.set_bit(bits - 1) },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -35,4 +120,107 @@ mod tests {
         assert_eq!(26_896, gray_code(19_999));
         assert_eq!(357_913_941, gray_code(429_496_729));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn decode_tests() {
+        for n in 0..=u16::MAX as u32 {
+            assert_eq!(n, gray_decode(gray_code(n)));
+        }
+        assert_eq!(u32::MAX, gray_decode(1 << 31));
+        assert_eq!(u64::MAX, (1u64 << 63).gray_decode());
+        assert_eq!(u128::MAX - 1, (u128::MAX - 1).gray_code().gray_decode());
+        assert_eq!(0b1011, 0b1110u8.gray_decode());
+    }
+
+    #[test]
+    fn increment_tests() {
+        for n in 0..=u16::MAX as u32 {
+            assert_eq!(gray_code(n + 1), gray_increment(gray_code(n)));
+        }
+        assert_eq!(0, u8::MAX.gray_code().gray_increment());
+        assert_eq!(
+            (u64::MAX / 3 + 1).gray_code(),
+            (u64::MAX / 3).gray_code().gray_increment()
+        );
+        assert_eq!(u128::MAX.gray_code(), (u128::MAX - 1).gray_code().gray_increment());
+    }
+
+    #[test]
+    fn sequence_tests() {
+        for bits in 0..=12 {
+            let codes: Vec<u32> = gray_sequence(bits).collect();
+
+            assert_eq!(1 << bits, codes.len());
+            for (i, code) in codes.iter().enumerate() {
+                assert_eq!(gray_code(i as u32), *code);
+                if i > 0 {
+                    assert_eq!(1, (code ^ codes[i - 1]).count_ones());
+                }
+            }
+        }
+        assert_eq!(256, gray_sequence::<u8>(8).count());
+        assert_eq!(Some(3), gray_sequence::<u128>(128).nth(2));
+        assert_eq!(vec![0u64], gray_sequence::<u64>(0).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid number of bits")]
+    fn sequence_too_many_bits_test() {
+        gray_sequence::<u16>(17);
+    }
+
+    #[test]
+    fn balanced_tests() {
+        for bits in 1..=5 {
+            let codes: Vec<u32> = balanced::balanced_gray_code(bits);
+            let counts: Vec<u32> = balanced::transition_counts(&codes);
+            let mut sorted: Vec<u32> = codes.clone();
+
+            sorted.sort();
+            assert_eq!((0..1 << bits).collect::<Vec<u32>>(), sorted);
+            for (i, code) in codes.iter().enumerate() {
+                assert_eq!(1, (code ^ codes[(i + 1) % codes.len()]).count_ones());
+            }
+            assert!(counts.iter().max().unwrap() - counts.iter().min().unwrap() <= 2);
+            assert_eq!(1 << bits, counts.iter().sum::<u32>());
+        }
+        // the reflected code flips the lowest bit 8 times out of 16
+        let reflected: Vec<u32> = gray_sequence(4).collect();
+        assert_eq!(vec![8, 4, 2, 2], balanced::transition_counts(&reflected));
+        let mut counts: Vec<u32> = balanced::transition_counts(&balanced::balanced_gray_code(5));
+        counts.sort();
+        assert_eq!(vec![6, 6, 6, 6, 8], counts);
+    }
+
+    #[test]
+    fn nary_tests() {
+        for (base, digits) in [(2, 8), (3, 4), (5, 3), (10, 2)] {
+            let total: u64 = (base as u64).pow(digits);
+            let mut previous: Option<Vec<u32>> = None;
+
+            for n in 0..total {
+                let code: Vec<u32> = nary::nary_gray_code(n, base, digits);
+
+                assert_eq!(n, nary::nary_gray_decode(&code, base));
+                // a single digit changes, by 1 modulo the base
+                if let Some(previous) = previous {
+                    let changed: Vec<usize> = (0..code.len()).filter(|i: &usize| code[*i] != previous[*i]).collect();
+
+                    assert_eq!(1, changed.len());
+                    assert_eq!((previous[changed[0]] + 1) % base, code[changed[0]]);
+                }
+                previous = Some(code);
+            }
+        }
+        // the base 2 code is the reflected gray code
+        let code: Vec<u32> = nary::nary_gray_code(200, 2, 8);
+        assert_eq!(gray_code(200), code.iter().rev().fold(0, |acc: u32, digit: &u32| acc << 1 | digit));
+        assert_eq!(u64::MAX, nary::nary_gray_decode(&nary::nary_gray_code(u64::MAX, 7, 23), 7));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid number of digits")]
+    fn nary_too_many_digits_test() {
+        nary::nary_gray_code(27, 3, 3);
+    }
+}
@@ -0,0 +1,81 @@
+/// Get the modular base-k Gray code of n, as its digits from the lowest one:
+/// two consecutive codes only differ by one digit, which changes by 1 modulo the base
+/// # Arguments
+/// * `n` - The number to encode
+/// * `base` - The base of the digits, at least 2
+/// * `digits` - The number of digits of the code, n should be less than base ^ digits
+/// # Panics
+/// If the base is less than 2 or if n doesn't fit in the digits
+/// # Examples
+/// ```
+/// use ex02::nary::nary_gray_code;
+///
+/// assert_eq!(vec![2, 0], nary_gray_code(2, 3, 2));
+/// assert_eq!(vec![2, 1], nary_gray_code(3, 3, 2));
+/// assert_eq!(vec![0, 1], nary_gray_code(4, 3, 2));
+/// ```
+pub fn nary_gray_code(n: u64, base: u32, digits: u32) -> Vec<u32> {
+    let mut res: Vec<u32> = get_digits(n, base, digits);
+
+    /*
+        each digit of the code is the difference between the digit of n
+        and the next higher one modulo the base: g(i) = (d(i) - d(i + 1)) mod k
+        for the base 2 this is n ^ (n >> 1)
+     */
+    for i in 0..res.len() {
+        let higher: u32 = if i + 1 < res.len() { res[i + 1] } else { 0 };
+
+        res[i] = (res[i] + base - higher) % base;
+    }
+
+    res
+}
+
+/// Get the number from its modular base-k Gray code
+/// # Arguments
+/// * `code` - The digits of the code from the lowest one
+/// * `base` - The base of the digits, at least 2
+/// # Panics
+/// If the base is less than 2, if a digit is not less than the base
+/// or if the number doesn't fit in a u64
+/// # Examples
+/// ```
+/// use ex02::nary::nary_gray_decode;
+///
+/// assert_eq!(4, nary_gray_decode(&[0, 1], 3));
+/// ```
+pub fn nary_gray_decode(code: &[u32], base: u32) -> u64 {
+    if base < 2 || code.iter().any(|digit: &u32| *digit >= base) {
+        panic!("Invalid base");
+    }
+    let mut res: u64 = 0;
+    let mut higher: u32 = 0;
+
+    // rebuild the digits of n from the highest one: d(i) = (g(i) + d(i + 1)) mod k
+    for digit in code.iter().rev() {
+        higher = (digit + higher) % base;
+        res = res
+            .checked_mul(base as u64)
+            .and_then(|res: u64| res.checked_add(higher as u64))
+            .unwrap_or_else(|| panic!("Invalid code"));
+    }
+
+    res
+}
+
+fn get_digits(mut n: u64, base: u32, digits: u32) -> Vec<u32> {
+    if base < 2 {
+        panic!("Invalid base");
+    }
+    let mut res: Vec<u32> = Vec::with_capacity(digits as usize);
+
+    for _ in 0..digits {
+        res.push((n % base as u64) as u32);
+        n /= base as u64;
+    }
+    if n != 0 {
+        panic!("Invalid number of digits");
+    }
+
+    res
+}
@@ -0,0 +1,79 @@
+use std::fmt::Debug;
+
+/// An unsigned integer of a fixed width holding a Gray code
+pub trait Word: Copy + PartialEq + Debug {
+    /// The number of bits of the word
+    const BITS: u32;
+    /// The word with all the bits unset
+    const ZERO: Self;
+
+    /// Get the reflected Gray code of the word: n ^ (n >> 1)
+    fn gray_code(self) -> Self;
+
+    /// Get the word from its reflected Gray code
+    fn gray_decode(self) -> Self;
+
+    /// Get the next Gray code in the sequence without decoding the word,
+    /// the last code of the sequence wraps around to zero
+    fn gray_increment(self) -> Self;
+
+    /// Get the word with the bit at index i set
+    fn set_bit(self, i: u32) -> Self;
+}
+
+macro_rules! impl_word {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Word for $t {
+                const BITS: u32 = <$t>::BITS;
+                const ZERO: Self = 0;
+
+                fn gray_code(self) -> Self {
+                    self ^ (self >> 1)
+                }
+
+                fn gray_decode(self) -> Self {
+                    /*
+                        each bit of the word is the xor of the bits of the code
+                        from the highest one down to it, so we xor the code with
+                        all its shifts: n = g ^ (g >> 1) ^ (g >> 2) ^ ...
+                        the shifts by 1, 2, 4... cover all of them in log(BITS) steps
+                     */
+                    let mut res: Self = self;
+                    let mut shift: u32 = 1;
+
+                    while shift < Self::BITS {
+                        res ^= res >> shift;
+                        shift <<= 1;
+                    }
+
+                    res
+                }
+
+                fn gray_increment(self) -> Self {
+                    /*
+                        the parity of the code is the parity of the word, so:
+                        - if the word is even, flip the lowest bit
+                        - otherwise flip the bit on the left of the lowest set bit
+                     */
+                    if self.count_ones() % 2 == 0 {
+                        return self ^ 1;
+                    }
+                    let lowest: u32 = self.trailing_zeros();
+
+                    if lowest + 1 == Self::BITS {
+                        0
+                    } else {
+                        self ^ (1 << (lowest + 1))
+                    }
+                }
+
+                fn set_bit(self, i: u32) -> Self {
+                    self | (1 << i)
+                }
+            }
+        )*
+    };
+}
+
+impl_word!(u8, u16, u32, u64, u128, usize);
@@ -18,6 +18,27 @@ pub fn eval_formula(formula: &str) -> bool {
     tree.eval()
 }
 
+/// Synthesize a formula of the constants '1' and '0' into a structural Verilog module,
+/// see netlist::verilog_netlist
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// * `module` - The name of the module
+/// # Panics
+/// If the formula is invalid
+pub fn verilog_netlist(formula: &str, module: &str) -> String {
+    netlist::verilog_netlist(formula, module)
+}
+
+/// Synthesize a formula of the constants '1' and '0' into a BLIF model, see netlist::blif_netlist
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// * `model` - The name of the model
+/// # Panics
+/// If the formula is invalid
+pub fn blif_netlist(formula: &str, model: &str) -> String {
+    netlist::blif_netlist(formula, model)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -121,6 +142,24 @@ mod tests {
         assert!(!eval_formula("1!1!&"));
     }
 
+    #[test]
+    fn functional_operators_tests() {
+        assert!(eval_formula("00↑"));
+        assert!(eval_formula("01↑"));
+        assert!(eval_formula("10↑"));
+        assert!(!eval_formula("11↑"));
+        assert!(eval_formula("00↓"));
+        assert!(!eval_formula("01↓"));
+        assert!(!eval_formula("10↓"));
+        assert!(!eval_formula("11↓"));
+        assert!(eval_formula("00⊙"));
+        assert!(!eval_formula("01⊙"));
+        assert!(!eval_formula("10⊙"));
+        assert!(eval_formula("11⊙"));
+        assert!(eval_formula("11↑0↓1⊙"));
+        assert!(!eval_formula("11↑!0↓"));
+    }
+
     #[test]
     fn all_operators_tests() {
         assert!(!eval_formula("1111&=>11=|11=1!0=>^"));
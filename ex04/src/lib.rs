@@ -16,6 +16,20 @@ fn build_print_truth_table(formula: &str) -> Vec<Vec<char>> {
     res
 }
 
+/// Build and print the truth table of the given formula with the rows in the Gray code order
+/// # Arguments
+/// * `formula` - A string slice that holds the formula to be evaluated 
+fn build_print_gray_truth_table(formula: &str) -> Vec<Vec<char>> {
+    let mut truth_table: TruthTable = TruthTable::new(formula);
+
+    truth_table.set_gray_order(true);
+    truth_table.fill();
+    let res: Vec<Vec<char>> = truth_table.eval().clone();
+    truth_table.print();
+
+    res
+}
+
 /// Print the truth table of the given formula
 /// # Arguments
 /// * `formula` - A string slice that holds the formula to be evaluated
@@ -35,6 +49,47 @@ pub fn print_truth_table(formula: &str) {
     build_print_truth_table(formula);
 }
 
+/// Print the truth table of the given formula with the rows in the Gray code order,
+/// each row only differs from the previous one by the value of one variable
+/// # Arguments
+/// * `formula` - A string slice that holds the formula to be evaluated
+/// # Example
+/// ```
+/// use ex04::print_gray_truth_table;
+/// print_gray_truth_table("AB&");
+/// // Output:
+/// // | A | B | = |
+/// // |---|---|---|
+/// // | 0 | 0 | 0 |
+/// // | 0 | 1 | 0 |
+/// // | 1 | 1 | 1 |
+/// // | 1 | 0 | 0 |
+/// ```
+pub fn print_gray_truth_table(formula: &str) {
+    build_print_gray_truth_table(formula);
+}
+
+/// Synthesize a formula into a structural Verilog module,
+/// see netlist::verilog_netlist
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// * `module` - The name of the module
+/// # Panics
+/// If the formula is invalid
+pub fn verilog_netlist(formula: &str, module: &str) -> String {
+    netlist::verilog_netlist(formula, module)
+}
+
+/// Synthesize a formula into a BLIF model, see netlist::blif_netlist
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// * `model` - The name of the model
+/// # Panics
+/// If the formula is invalid
+pub fn blif_netlist(formula: &str, model: &str) -> String {
+    netlist::blif_netlist(formula, model)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -267,6 +322,25 @@ mod tests {
             ];
         res = format_table(build_print_truth_table(formula));
 
+        assert_eq!(expected, res);
+        /*-------------------------------------------------------------------------*/
+        /*------------------------ Test 7 ------------------------------------- */
+        formula = "AB↑C↓";
+        expected = 
+            vec![
+                format!("| {} | {} | {} | = |", 'A', 'B', 'C'),
+                format!("|---|---|---|---|"),
+                format!("| {} | {} | {} | {} |", '0', '0', '0', '0'),
+                format!("| {} | {} | {} | {} |", '0', '0', '1', '0'),
+                format!("| {} | {} | {} | {} |", '0', '1', '0', '0'),
+                format!("| {} | {} | {} | {} |", '0', '1', '1', '0'),
+                format!("| {} | {} | {} | {} |", '1', '0', '0', '0'),
+                format!("| {} | {} | {} | {} |", '1', '0', '1', '0'),
+                format!("| {} | {} | {} | {} |", '1', '1', '0', '1'),
+                format!("| {} | {} | {} | {} |", '1', '1', '1', '0'),
+            ];
+        res = format_table(build_print_truth_table(formula));
+
         assert_eq!(expected, res);
         /*-------------------------------------------------------------------------*/
     }
@@ -349,4 +423,38 @@ mod tests {
         build_print_truth_table("111|");
     }
 
+    #[test]
+    fn gray_order_tests() {
+        let expected: Vec<String> = vec![
+            format!("| {} | {} | = |", 'A', 'B'),
+            format!("|---|---|---|"),
+            format!("| {} | {} | {} |", '0', '0', '0'),
+            format!("| {} | {} | {} |", '0', '1', '1'),
+            format!("| {} | {} | {} |", '1', '1', '0'),
+            format!("| {} | {} | {} |", '1', '0', '1'),
+        ];
+
+        assert_eq!(expected, format_table(build_print_gray_truth_table("AB^")));
+
+        // the rows are the same as in the binary order, and consecutive rows differ by one variable
+        for formula in ["AB&C|", "XY=VK>=L&E^G|", "AB|C!&D>"] {
+            let gray: Vec<String> = format_table(build_print_gray_truth_table(formula));
+            let mut binary: Vec<String> = format_table(build_print_truth_table(formula));
+            let mut sorted: Vec<String> = gray.clone();
+
+            sorted[2..].sort();
+            binary[2..].sort();
+            assert_eq!(binary, sorted);
+            for i in 3..gray.len() {
+                let changes: usize = gray[i]
+                    .chars()
+                    .zip(gray[i - 1].chars())
+                    .take(gray[i].len() - 4)
+                    .filter(|(lhs, rhs)| lhs != rhs)
+                    .count();
+
+                assert_eq!(1, changes);
+            }
+        }
+    }
 }
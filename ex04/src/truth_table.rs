@@ -1,6 +1,7 @@
 mod ast;
 
 use ast::AST;
+use ex02::gray_code;
 use std::collections::BTreeSet;
 
 /// TruthTable struct
@@ -13,6 +14,7 @@ pub struct TruthTable {
     start_index: isize,
     zeros_to_fill: usize,
     fill_zero: bool,
+    gray_order: bool,
 }
 impl TruthTable {
     /// Get a new TruthTable instance
@@ -69,9 +71,18 @@ impl TruthTable {
             zeros_to_fill: 1,
             // a boolean to know if we should fill with zeros or switch to ones.
             fill_zero: true,
+            gray_order: false,
         }
     }
 
+    /// Fill the rows in the Gray code order instead of the binary counting order,
+    /// so each row only differs from the previous one by the value of one variable.
+    /// # Arguments
+    /// *`gray_order` -- A boolean to check if we want the Gray code order
+    pub fn set_gray_order(&mut self, gray_order: bool) {
+        self.gray_order = gray_order;
+    }
+
     /// Fill the header of the truth table with the unique characters of the formula.
     fn fill_table_header(&mut self) {
         let mut iter = self.unique_chars.iter();
@@ -106,6 +117,10 @@ impl TruthTable {
         let mut tmp: usize = self.zeros_to_fill;
 
         self.fill_table_header();
+        if self.gray_order {
+            self.fill_gray_order();
+            return;
+        }
         // Iterate over each column and start filling it with the zeros and ones, 
         /*
             Iterate over each column and start filling it with the zeros and ones
@@ -145,6 +160,29 @@ impl TruthTable {
         }
     }
 
+    /// Fill the truth table with the values of the variables in the Gray code order.
+    fn fill_gray_order(&mut self) {
+        let vars_cnt: usize = self.unique_chars.len();
+
+        for i in 2..self.height {
+            /*
+                The values of the row are the bits of the gray code of its index,
+                the first variable is the highest bit, same as the binary order:
+                    row 0: 00, row 1: 01, row 2: 11, row 3: 10
+             */
+            let code: u32 = gray_code((i - 2) as u32);
+
+            for k in 0..vars_cnt {
+                let bit: u32 = (code >> (vars_cnt - 1 - k)) & 1;
+
+                self.truth_table[i][2 + 4 * k] = if bit == 0 { '0' } else { '1' };
+            }
+            for j in (0..self.width).step_by(4) {
+                self.truth_table[i][j] = '|';
+            }
+        }
+    }
+
     /// Evaluate each row in the truth table and fill the last column with the result.
     pub fn eval(&mut self) -> &Vec<Vec<char>> {
 
@@ -177,4 +215,4 @@ impl TruthTable {
         }
     }
 
-}
\ No newline at end of file
+}
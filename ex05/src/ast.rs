@@ -1,9 +1,16 @@
+pub mod aig;
+pub mod anf;
+pub mod gates;
 pub mod nnf;
+pub mod rewrite;
+pub mod simplify;
+pub mod trace;
 
 use std::{cell::RefCell, fmt::Debug, rc::Rc};
+use trace::Trace;
 
 /// The possible tokens in the AST
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 enum Symbols {
     Char(char),
     Not,
@@ -12,6 +19,9 @@ enum Symbols {
     Xor,
     MatCond,
     LogEq,
+    Nand,
+    Nor,
+    Xnor,
 }
 
 type RcNode = Rc<RefCell<Option<Box<Node>>>>;
@@ -40,6 +50,8 @@ pub struct AST {
     stack: Vec<RcNode>,
     insert_left: bool,
     not_cnt: usize,
+    trace: Trace,
+    allow_const: bool,
 }
 
 impl AST {
@@ -50,10 +62,28 @@ impl AST {
             stack: Vec::new(),
             insert_left: false,
             not_cnt: 0,
+            trace: Trace::new(false),
+            allow_const: false,
         }
     }
 
-    /// Build the AST from a string
+    /// Record each rule applied by simplify_material_properties
+    pub fn enable_trace(&mut self) {
+        self.trace = Trace::new(true);
+    }
+
+    /// Get the rules applied on the tree since the trace was enabled
+    pub fn get_trace(&self) -> &Trace {
+        &self.trace
+    }
+
+    /// Accept the constants '1' and '0' mixed with the vars in the formulas built after it
+    pub fn allow_constants(&mut self) {
+        self.allow_const = true;
+    }
+
+    /// Build the AST from a string, the vars are only mixed with the constants '1' and '0'
+    /// after a call to allow_constants
     /// # Arguments
     /// * `formula` - A string slice that holds the formula
     /// * `allow_var` - A boolean to check if we want to use vars or '1' and '0'
@@ -63,22 +93,23 @@ impl AST {
         let mut stack: Vec<char> = Vec::new();
         let mut is_oper: bool = false;
         let mut processed: usize = 0;
+        let formula_len: usize = formula.chars().count();
 
 
-        for c in formula.as_bytes() {
+        for c in formula.chars() {
             match c {
-                b'1' | b'0' if !allow_var => stack.push(*c as char),
-                b'A'..=b'Z' if allow_var => stack.push(*c as char),
-                b'|' => self.add_sub_tree(&mut stack, Symbols::Or),
-                b'&' => self.add_sub_tree(&mut stack, Symbols::And),
-                b'!' => {
+                '1' | '0' if !allow_var || self.allow_const => stack.push(c),
+                'A'..='Z' if allow_var => stack.push(c),
+                '|' => self.add_sub_tree(&mut stack, Symbols::Or),
+                '&' => self.add_sub_tree(&mut stack, Symbols::And),
+                '!' => {
                     /*
                         if the previous character was an operator or if we are at the end of the
                         formula, we should add a new not node to the tree, otherwise we should
                         add the negation operator to the stack to use it again in the next iteration.
                      */
-                    if is_oper || (formula.len() - processed == 1) {
-                        self.add_not_node(&mut stack, formula.len() - processed == 1);
+                    if is_oper || (formula_len - processed == 1) {
+                        self.add_not_node(&mut stack, formula_len - processed == 1);
                     } else {
                         let top: char = stack.pop().unwrap_or_else(|| {
                             panic!("Invalid formula");
@@ -90,16 +121,19 @@ impl AST {
                          */
                         if top != '!' {
                             stack.push(top);
-                            stack.push(*c as char);
+                            stack.push(c);
                             self.not_cnt += 1;
                         } else {
                             self.not_cnt -= 1;
                         }
                     }
                 }
-                b'^' => self.add_sub_tree(&mut stack, Symbols::Xor),
-                b'>' => self.add_sub_tree(&mut stack, Symbols::MatCond),
-                b'=' => self.add_sub_tree(&mut stack, Symbols::LogEq),
+                '^' => self.add_sub_tree(&mut stack, Symbols::Xor),
+                '>' => self.add_sub_tree(&mut stack, Symbols::MatCond),
+                '=' => self.add_sub_tree(&mut stack, Symbols::LogEq),
+                '↑' => self.add_sub_tree(&mut stack, Symbols::Nand),
+                '↓' => self.add_sub_tree(&mut stack, Symbols::Nor),
+                '⊙' => self.add_sub_tree(&mut stack, Symbols::Xnor),
                 _ => panic!("Invalid formula"),
             }
             /*
@@ -114,14 +148,16 @@ impl AST {
                 2. if we have a formula like "A!!!!", when we find a negation operator, we just
                 check if should add it to stack if the top of the stack is not a negation operator.
              */
-            if allow_var {
-                is_oper = if (*c < b'A' || *c > b'Z') && *c != b'!' {
+            if allow_var && self.allow_const {
+                is_oper = !c.is_ascii_uppercase() && c != '1' && c != '0' && c != '!';
+            } else if allow_var {
+                is_oper = if !c.is_ascii_uppercase() && c != '!' {
                     true
                 } else {
                     false
                 };
             } else {
-                is_oper = if *c != b'1' && *c != b'0' && *c != b'!' {
+                is_oper = if c != '1' && c != '0' && c != '!' {
                     true
                 } else {
                     false
@@ -150,33 +186,39 @@ impl AST {
     }
 
     /// Simplify the material properties by applying the following rules:
-    /// 1. Rewrite the equivalence operator
-    /// 2. Rewrite the material conditions
-    /// 3. Rewrite the xor operator
-    /// 4. Eliminate double negation
-    /// 5. Apply the Morgan's law
+    /// 1. Rewrite the NAND, NOR and XNOR operators
+    /// 2. Rewrite the equivalence operator
+    /// 3. Rewrite the material conditions
+    /// 4. Rewrite the xor operator
+    /// 5. Eliminate double negation
+    /// 6. Apply the Morgan's law
     pub fn simplify_material_properties(&mut self) {
+        /*
+            Apply the Rewrite functional operators rule on the tree:
+            A ↑ B <=> !(A & B), A ↓ B <=> !(A | B), A ⊙ B <=> (A <=> B)
+         */
+        nnf::rewrite_functional_operators(Rc::clone(&self.root), &mut self.trace);
         /*
             Apply the Rewrite equivalence rule on the tree:
             (A <=> B) <=> (A => B) & (B => A)
          */
-        nnf::rewrite_equivalence(Rc::clone(&self.root));
+        nnf::rewrite_equivalence(Rc::clone(&self.root), &mut self.trace);
         /*
             Apply the Rewrite material conditions rule on the tree:
             (A => B) <=> !A | B
          */
-        nnf::rewrite_material_conditions(Rc::clone(&self.root));
+        nnf::rewrite_material_conditions(Rc::clone(&self.root), &mut self.trace);
         /*
             Apply the Rewrite xor operator rule on the tree:
             A ^ B <=> (A & !B) | (B & !A)
          */
-        nnf::rewrite_xor_operator(Rc::clone(&self.root));
+        nnf::rewrite_xor_operator(Rc::clone(&self.root), &mut self.trace);
         /*
             Eliminate double negation from the tree:
             !!A <=> A
          */
-        self.root = nnf::eliminate_double_negation(Rc::clone(&self.root), 0);
-        nnf::remove_double_negations(Rc::clone(&self.root));
+        self.root = nnf::eliminate_double_negation(Rc::clone(&self.root), 0, &mut self.trace);
+        nnf::remove_double_negations(Rc::clone(&self.root), &mut self.trace);
         /*
             Apply the Morgan's law on the tree:
             !(A & B) <=> !A | !B
@@ -199,12 +241,51 @@ impl AST {
 
             self.root = right_subtree;
             if call_subtree {
-                nnf::morgan_law(Rc::clone(&self.root), is_not_node);
+                nnf::morgan_law(Rc::clone(&self.root), is_not_node, &mut self.trace);
             }
         } else {
-            nnf::morgan_law(Rc::clone(&self.root), false);
+            nnf::morgan_law(Rc::clone(&self.root), false, &mut self.trace);
         }
-        nnf::remove_double_negations(Rc::clone(&self.root));
+        nnf::remove_double_negations(Rc::clone(&self.root), &mut self.trace);
+    }
+
+    /// Apply the rules of the rewrite system on the tree until no rule matches anymore,
+    /// and return the number of applied rules
+    /// # Arguments
+    /// * `system` - The rewrite system holding the rules and the strategy to apply them
+    pub fn rewrite(&mut self, system: &rewrite::RewriteSystem) -> usize {
+        system.rewrite(Rc::clone(&self.root), &mut self.trace)
+    }
+
+    /// Simplify the tree using the algebraic laws (identity, annihilation, idempotence,
+    /// complementation, absorption) and by propagating the constants '1' and '0'
+    pub fn simplify(&mut self) {
+        self.root = simplify::simplify(Rc::clone(&self.root), &mut self.trace);
+    }
+
+    /// Rewrite the tree using only the NAND or the NOR gate
+    /// # Arguments
+    /// * `gate` - The universal gate used to rewrite the tree
+    pub fn rewrite_with_gate(&mut self, gate: gates::Gate) {
+        /*
+            Start by rewriting every operator using the NOT, AND and OR operators,
+            then map these operators on the gate.
+         */
+        nnf::rewrite_functional_operators(Rc::clone(&self.root), &mut self.trace);
+        nnf::rewrite_equivalence(Rc::clone(&self.root), &mut self.trace);
+        nnf::rewrite_material_conditions(Rc::clone(&self.root), &mut self.trace);
+        nnf::rewrite_xor_operator(Rc::clone(&self.root), &mut self.trace);
+        self.root = gates::rewrite_with_gate(Rc::clone(&self.root), false, gate);
+    }
+
+    /// Get the algebraic normal form of the tree, a XOR of conjunctions of vars
+    pub fn get_anf(&self) -> anf::Anf {
+        anf::Anf::new(self)
+    }
+
+    /// Get the And-Inverter Graph of the tree, with the constants propagated and the identical nodes shared
+    pub fn get_aig(&self) -> aig::Aig {
+        aig::Aig::from_ast(self)
     }
 
     /// Get the RPN formula using Post Order Traversal
@@ -376,6 +457,18 @@ impl AST {
                 self.eval_tree(root.as_ref().unwrap().left.borrow().as_ref())
                     == self.eval_tree(root.as_ref().unwrap().right.borrow().as_ref())
             }
+            Symbols::Nand => {
+                !(self.eval_tree(root.as_ref().unwrap().left.borrow().as_ref())
+                    & self.eval_tree(root.as_ref().unwrap().right.borrow().as_ref()))
+            }
+            Symbols::Nor => {
+                !(self.eval_tree(root.as_ref().unwrap().left.borrow().as_ref())
+                    | self.eval_tree(root.as_ref().unwrap().right.borrow().as_ref()))
+            }
+            Symbols::Xnor => {
+                self.eval_tree(root.as_ref().unwrap().left.borrow().as_ref())
+                    == self.eval_tree(root.as_ref().unwrap().right.borrow().as_ref())
+            }
             Symbols::Not => {
                 !self.eval_tree(root.as_ref().unwrap().right.borrow().as_ref())
             },
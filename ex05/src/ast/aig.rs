@@ -0,0 +1,710 @@
+use super::*;
+use std::collections::{BTreeSet, HashMap};
+
+/// A literal of the AIG: the index of a node times two, plus one if the edge is inverted.
+/// The node 0 is the constant false, so the literal 0 is false and the literal 1 is true.
+pub type Literal = u32;
+
+/// An And-Inverter Graph: every operator is rewritten using two-input AND nodes
+/// and inverted edges. The nodes are numbered like in the AIGER format: the constant
+/// first, then the inputs, then the AND nodes, each AND node only reads the nodes before it.
+#[derive(Debug, Clone)]
+pub struct Aig {
+    inputs: Vec<char>,
+    ands: Vec<(Literal, Literal)>,
+    levels: Vec<usize>,
+    table: HashMap<(Literal, Literal), Literal>,
+    output: Literal,
+}
+
+impl Aig {
+    /// Get a new AIG with the given inputs and the constant false as output
+    /// # Arguments
+    /// * `inputs` - The names of the inputs
+    /// # Panics
+    /// If a name is used twice
+    pub fn new(inputs: &[char]) -> Self {
+        let unique: BTreeSet<char> = inputs.iter().copied().collect();
+
+        if unique.len() != inputs.len() {
+            panic!("Invalid inputs");
+        }
+
+        Self {
+            inputs: inputs.to_vec(),
+            ands: Vec::new(),
+            levels: Vec::new(),
+            table: HashMap::new(),
+            output: 0,
+        }
+    }
+
+    /// Build the AIG of the formula held by the AST
+    /// # Arguments
+    /// * `tree` - The AST of the formula
+    pub fn from_ast(tree: &AST) -> Self {
+        let mut vars: BTreeSet<char> = BTreeSet::new();
+
+        get_vars(Rc::clone(&tree.root), &mut vars);
+        let mut aig: Aig = Aig::new(&vars.into_iter().collect::<Vec<char>>());
+
+        aig.output = aig.add_subtree(Rc::clone(&tree.root));
+        aig
+    }
+
+    /// Get the names of the inputs
+    pub fn get_inputs(&self) -> &Vec<char> {
+        &self.inputs
+    }
+
+    /// Get the literal of the input with the given name
+    /// # Panics
+    /// If the AIG has no such input
+    pub fn get_input(&self, name: char) -> Literal {
+        match self.inputs.iter().position(|c: &char| *c == name) {
+            Some(i) => 2 * (i as Literal + 1),
+            None => panic!("Invalid input"),
+        }
+    }
+
+    /// Get the literal of the output
+    pub fn get_output(&self) -> Literal {
+        self.output
+    }
+
+    /// Set the literal driving the output of the AIG
+    /// # Panics
+    /// If the literal is not a node of the AIG
+    pub fn set_output(&mut self, output: Literal) {
+        self.check_literal(output);
+        self.output = output;
+    }
+
+    /// Get the operands of the AND nodes, in the order of the nodes
+    pub fn get_ands(&self) -> &Vec<(Literal, Literal)> {
+        &self.ands
+    }
+
+    /// Get the number of AND nodes
+    pub fn and_count(&self) -> usize {
+        self.ands.len()
+    }
+
+    /// Get the number of AND nodes on the longest path from an input to the output
+    pub fn depth(&self) -> usize {
+        self.get_level(self.output)
+    }
+
+    /// Get the negation of a literal, which only inverts the edge
+    pub fn not(&self, a: Literal) -> Literal {
+        a ^ 1
+    }
+
+    /// Get the literal of a & b, the AND node is only added if it can't be
+    /// simplified and if the same node doesn't exist yet
+    pub fn and(&mut self, a: Literal, b: Literal) -> Literal {
+        self.check_literal(a);
+        self.check_literal(b);
+        /*
+            constant propagation:
+            A & 0 <=> 0, A & 1 <=> A, A & A <=> A, A & !A <=> 0
+         */
+        if a == 0 || b == 0 || a == self.not(b) {
+            return 0;
+        }
+        if a == 1 || a == b {
+            return b;
+        }
+        if b == 1 {
+            return a;
+        }
+        // structural hashing, the operands are sorted to share A & B and B & A
+        let key: (Literal, Literal) = (a.max(b), a.min(b));
+
+        if let Some(res) = self.table.get(&key) {
+            return *res;
+        }
+        let res: Literal = 2 * (1 + self.inputs.len() + self.ands.len()) as Literal;
+
+        self.levels.push(1 + self.get_level(a).max(self.get_level(b)));
+        self.ands.push(key);
+        self.table.insert(key, res);
+        res
+    }
+
+    /// Get the literal of a | b <=> !(!a & !b)
+    pub fn or(&mut self, a: Literal, b: Literal) -> Literal {
+        let res: Literal = self.and(self.not(a), self.not(b));
+
+        self.not(res)
+    }
+
+    /// Get the literal of a ^ b <=> (a & !b) | (!a & b)
+    pub fn xor(&mut self, a: Literal, b: Literal) -> Literal {
+        let lhs: Literal = self.and(a, self.not(b));
+        let rhs: Literal = self.and(self.not(a), b);
+
+        self.or(lhs, rhs)
+    }
+
+    /// Evaluate the AIG
+    /// # Arguments
+    /// * `inputs` - The value of each input, in the order of get_inputs
+    /// # Panics
+    /// If the number of values doesn't match the number of inputs
+    pub fn eval(&self, inputs: &[bool]) -> bool {
+        if inputs.len() != self.inputs.len() {
+            panic!("Invalid number of inputs");
+        }
+        let mut values: Vec<bool> = vec![false];
+
+        values.extend_from_slice(inputs);
+        for (a, b) in self.ands.iter() {
+            let value: bool = get_value(&values, *a) & get_value(&values, *b);
+
+            values.push(value);
+        }
+
+        get_value(&values, self.output)
+    }
+
+    /// Convert the AIG back to an AST using only the AND and NOT operators,
+    /// the shared nodes are copied in each of their parents
+    pub fn to_ast(&self) -> AST {
+        let mut tree: AST = AST::new();
+
+        tree.root = self.get_subtree(self.output);
+        tree
+    }
+
+    /// Remove the nodes which are not used by the output and renumber the others
+    pub fn cleanup(&mut self) {
+        *self = self.rebuild(|aig: &mut Aig, a: Literal, b: Literal| aig.and(a, b));
+    }
+
+    /// Reduce the depth of the AIG: the chains of AND nodes are collected into
+    /// a single multi-input AND, which is rebuilt as a balanced tree
+    pub fn balance(&mut self) {
+        /*
+            A chain stops at an inverted edge or at a node used by several parents,
+            so no node needs to be duplicated. The inputs of the chain are combined
+            from the lowest ones, so the deepest input gets the shortest path:
+                ((((A & B) & C) & D) & E)  =>  ((A & B) & (C & D)) & E
+         */
+        let fan_outs: Vec<usize> = self.get_fan_outs();
+        let mut res: Aig = Aig::new(&self.inputs);
+        let mut memo: HashMap<Literal, Literal> = HashMap::new();
+        let output: Literal = self.balance_node(self.output & !1, &fan_outs, &mut res, &mut memo);
+
+        res.output = output ^ (self.output & 1);
+        *self = res;
+    }
+
+    /// Reduce the number of AND nodes by applying the two-level rules on each AND node:
+    /// * contradiction: (A & B) & !A <=> 0
+    /// * idempotence: (A & B) & A <=> A & B
+    /// * subsumption: !(A & B) & !A <=> !A
+    /// * substitution: !(A & B) & A <=> A & !B
+    /// * resolution: !(A & B) & !(A & !B) <=> !A
+    pub fn rewrite(&mut self) {
+        *self = self.rebuild(|aig: &mut Aig, a: Literal, b: Literal| aig.rewrite_and(a, b));
+        // the operands of a rewritten node are built before it, so they may not be used anymore
+        self.cleanup();
+    }
+
+    /// Export the AIG in the ASCII AIGER format, with the names of the inputs in the symbol table
+    pub fn to_aag(&self) -> String {
+        let mut res: String = self.get_aiger_header("aag");
+
+        for i in 0..self.inputs.len() {
+            res += &format!("{}\n", 2 * (i + 1));
+        }
+        res += &format!("{}\n", self.output);
+        for (i, (a, b)) in self.ands.iter().enumerate() {
+            res += &format!("{} {} {}\n", self.get_and_literal(i), a, b);
+        }
+        res += &self.get_aiger_symbols();
+
+        res
+    }
+
+    /// Export the AIG in the binary AIGER format, with the names of the inputs in the symbol table
+    pub fn to_aig(&self) -> Vec<u8> {
+        /*
+            In the binary format the inputs are implicit, and each AND node is
+            stored as the two deltas lhs - rhs0 and rhs0 - rhs1, where lhs > rhs0 >= rhs1,
+            each delta is encoded with 7 bits by byte, the highest bit is set when
+            more bytes follow.
+         */
+        let mut res: Vec<u8> = self.get_aiger_header("aig").into_bytes();
+
+        res.extend(format!("{}\n", self.output).into_bytes());
+        for (i, (a, b)) in self.ands.iter().enumerate() {
+            encode_delta(&mut res, self.get_and_literal(i) - a);
+            encode_delta(&mut res, a - b);
+        }
+        res.extend(self.get_aiger_symbols().into_bytes());
+
+        res
+    }
+
+    /// Import an AIG from the ASCII AIGER format, the AIG must have no latch and a single output.
+    /// The inputs are named by the symbol table when it holds a letter, otherwise by
+    /// their index: A, B, C... The nodes are simplified and hashed while they are imported.
+    /// # Panics
+    /// If the content is not a valid AIGER file
+    pub fn from_aag(content: &str) -> Self {
+        let mut lines = content.lines();
+        let (max_var, input_cnt, and_cnt) = parse_aiger_header(lines.next(), "aag");
+        let mut definitions: HashMap<Literal, (Literal, Literal)> = HashMap::new();
+
+        for i in 0..input_cnt {
+            if parse_literals(lines.next(), 1)[0] != 2 * (i as Literal + 1) {
+                panic!("Invalid AIGER file");
+            }
+        }
+        let output: Literal = parse_literals(lines.next(), 1)[0];
+
+        for _ in 0..and_cnt {
+            let literals: Vec<Literal> = parse_literals(lines.next(), 3);
+
+            if literals[0] & 1 != 0
+                || literals[0] <= 2 * input_cnt as Literal
+                || definitions.insert(literals[0], (literals[1], literals[2])).is_some()
+            {
+                panic!("Invalid AIGER file");
+            }
+        }
+        let names: Vec<char> = parse_aiger_symbols(lines, input_cnt);
+
+        import_aiger(&names, max_var, &definitions, output)
+    }
+
+    /// Import an AIG from the binary AIGER format, like from_aag
+    /// # Panics
+    /// If the content is not a valid AIGER file
+    pub fn from_aig(content: &[u8]) -> Self {
+        let mut pos: usize = 0;
+        let (max_var, input_cnt, and_cnt) = parse_aiger_header(read_line(content, &mut pos), "aig");
+        let output: Literal = parse_literals(read_line(content, &mut pos), 1)[0];
+        let mut definitions: HashMap<Literal, (Literal, Literal)> = HashMap::new();
+
+        for i in 0..and_cnt {
+            let lhs: Literal = 2 * (1 + input_cnt + i) as Literal;
+            let a: Literal = lhs.checked_sub(decode_delta(content, &mut pos)).unwrap_or_else(|| {
+                panic!("Invalid AIGER file");
+            });
+            let b: Literal = a.checked_sub(decode_delta(content, &mut pos)).unwrap_or_else(|| {
+                panic!("Invalid AIGER file");
+            });
+
+            definitions.insert(lhs, (a, b));
+        }
+        let symbols: &str = std::str::from_utf8(&content[pos..]).unwrap_or_else(|_| {
+            panic!("Invalid AIGER file");
+        });
+        let names: Vec<char> = parse_aiger_symbols(symbols.lines(), input_cnt);
+
+        import_aiger(&names, max_var, &definitions, output)
+    }
+
+    fn add_subtree(&mut self, curr_node: RcNode) -> Literal {
+        let node = curr_node.borrow();
+        let node: &Node = node.as_ref().unwrap_or_else(|| {
+            panic!("Invalid formula");
+        });
+
+        match node.data {
+            Symbols::Char('1') => 1,
+            Symbols::Char('0') => 0,
+            Symbols::Char(c) => self.get_input(c),
+            Symbols::Not => {
+                let a: Literal = self.add_subtree(Rc::clone(&node.right));
+
+                self.not(a)
+            }
+            _ => {
+                let a: Literal = self.add_subtree(Rc::clone(&node.left));
+                let b: Literal = self.add_subtree(Rc::clone(&node.right));
+
+                match node.data {
+                    Symbols::And => self.and(a, b),
+                    Symbols::Or => self.or(a, b),
+                    Symbols::Xor => self.xor(a, b),
+                    Symbols::MatCond => self.or(self.not(a), b),
+                    Symbols::Nand => {
+                        let res: Literal = self.and(a, b);
+
+                        self.not(res)
+                    }
+                    Symbols::Nor => {
+                        let res: Literal = self.or(a, b);
+
+                        self.not(res)
+                    }
+                    _ => {
+                        let res: Literal = self.xor(a, b);
+
+                        self.not(res)
+                    }
+                }
+            }
+        }
+    }
+
+    fn get_subtree(&self, lit: Literal) -> RcNode {
+        let node: Node = match self.get_node(lit) {
+            _ if lit & 1 != 0 && lit > 1 => {
+                let mut node: Node = Node::new(Symbols::Not);
+
+                node.right = self.get_subtree(lit & !1);
+                node
+            }
+            Some((a, b)) => {
+                let mut node: Node = Node::new(Symbols::And);
+
+                // the operands are stored with the highest literal first
+                node.left = self.get_subtree(b);
+                node.right = self.get_subtree(a);
+                node
+            }
+            None if lit < 2 => Node::new(Symbols::Char(if lit == 1 { '1' } else { '0' })),
+            None => Node::new(Symbols::Char(self.inputs[(lit / 2 - 1) as usize])),
+        };
+
+        Rc::new(RefCell::new(Some(Box::new(node))))
+    }
+
+    /// Get the operands of the AND node of the literal, or None if it's an input or the constant
+    fn get_node(&self, lit: Literal) -> Option<(Literal, Literal)> {
+        let var: usize = (lit / 2) as usize;
+
+        if var <= self.inputs.len() {
+            return None;
+        }
+
+        Some(self.ands[var - self.inputs.len() - 1])
+    }
+
+    fn get_level(&self, lit: Literal) -> usize {
+        let var: usize = (lit / 2) as usize;
+
+        if var <= self.inputs.len() {
+            return 0;
+        }
+
+        self.levels[var - self.inputs.len() - 1]
+    }
+
+    fn get_and_literal(&self, index: usize) -> Literal {
+        2 * (1 + self.inputs.len() + index) as Literal
+    }
+
+    fn get_fan_outs(&self) -> Vec<usize> {
+        let mut fan_outs: Vec<usize> = vec![0; 1 + self.inputs.len() + self.ands.len()];
+
+        for (a, b) in self.ands.iter() {
+            fan_outs[(a / 2) as usize] += 1;
+            fan_outs[(b / 2) as usize] += 1;
+        }
+        fan_outs[(self.output / 2) as usize] += 1;
+
+        fan_outs
+    }
+
+    fn check_literal(&self, lit: Literal) {
+        if (lit / 2) as usize > self.inputs.len() + self.ands.len() {
+            panic!("Invalid literal");
+        }
+    }
+
+    /// Copy the nodes used by the output into a new AIG, building each AND node with the given function
+    fn rebuild(&self, and: impl Fn(&mut Aig, Literal, Literal) -> Literal) -> Aig {
+        let mut res: Aig = Aig::new(&self.inputs);
+        let mut memo: HashMap<Literal, Literal> = HashMap::new();
+        let output: Literal = self.copy_node(self.output & !1, &mut res, &mut memo, &and);
+
+        res.output = output ^ (self.output & 1);
+        res
+    }
+
+    fn copy_node(
+        &self,
+        lit: Literal,
+        res: &mut Aig,
+        memo: &mut HashMap<Literal, Literal>,
+        and: &impl Fn(&mut Aig, Literal, Literal) -> Literal,
+    ) -> Literal {
+        let Some((a, b)) = self.get_node(lit) else {
+            return lit;
+        };
+        if let Some(new_lit) = memo.get(&lit) {
+            return *new_lit;
+        }
+        let new_a: Literal = self.copy_node(a & !1, res, memo, and) ^ (a & 1);
+        let new_b: Literal = self.copy_node(b & !1, res, memo, and) ^ (b & 1);
+        let new_lit: Literal = and(res, new_a, new_b);
+
+        memo.insert(lit, new_lit);
+        new_lit
+    }
+
+    fn balance_node(
+        &self,
+        lit: Literal,
+        fan_outs: &[usize],
+        res: &mut Aig,
+        memo: &mut HashMap<Literal, Literal>,
+    ) -> Literal {
+        if self.get_node(lit).is_none() {
+            return lit;
+        }
+        if let Some(new_lit) = memo.get(&lit) {
+            return *new_lit;
+        }
+        let mut leaves: Vec<Literal> = Vec::new();
+
+        self.collect_chain(lit, true, fan_outs, &mut leaves);
+        let mut operands: Vec<Literal> = leaves
+            .iter()
+            .map(|leaf: &Literal| self.balance_node(leaf & !1, fan_outs, res, memo) ^ (leaf & 1))
+            .collect();
+
+        while operands.len() > 1 {
+            // keep the deepest operands first, so the two lowest ones are at the end
+            operands.sort_by_key(|operand: &Literal| std::cmp::Reverse(res.get_level(*operand)));
+            let a: Literal = operands.pop().unwrap();
+            let b: Literal = operands.pop().unwrap();
+
+            operands.push(res.and(a, b));
+        }
+        memo.insert(lit, operands[0]);
+        operands[0]
+    }
+
+    fn collect_chain(&self, lit: Literal, is_root: bool, fan_outs: &[usize], leaves: &mut Vec<Literal>) {
+        match self.get_node(lit) {
+            Some((a, b)) if lit & 1 == 0 && (is_root || fan_outs[(lit / 2) as usize] == 1) => {
+                self.collect_chain(a, false, fan_outs, leaves);
+                self.collect_chain(b, false, fan_outs, leaves);
+            }
+            _ => leaves.push(lit),
+        }
+    }
+
+    fn rewrite_and(&mut self, a: Literal, b: Literal) -> Literal {
+        for (lhs, rhs) in [(a, b), (b, a)] {
+            let Some((x, y)) = self.get_node(lhs) else {
+                continue;
+            };
+
+            if lhs & 1 == 0 {
+                // (x & y) & x <=> x & y
+                if rhs == x || rhs == y {
+                    return lhs;
+                }
+                // (x & y) & !x <=> 0
+                if rhs == self.not(x) || rhs == self.not(y) {
+                    return 0;
+                }
+                // (x & y) & (z & w) <=> 0 if one operand is the negation of another one
+                if let Some((z, w)) = self.get_node(rhs).filter(|_| rhs & 1 == 0) {
+                    if [z, w].iter().any(|lit: &Literal| *lit == self.not(x) || *lit == self.not(y)) {
+                        return 0;
+                    }
+                }
+            } else {
+                // !(x & y) & !x <=> !x
+                if rhs == self.not(x) || rhs == self.not(y) {
+                    return rhs;
+                }
+                // !(x & y) & x <=> x & !y
+                if rhs == x {
+                    return self.and(rhs, self.not(y));
+                }
+                if rhs == y {
+                    return self.and(rhs, self.not(x));
+                }
+                // !(x & y) & !(x & !y) <=> !x
+                if let Some((z, w)) = self.get_node(rhs).filter(|_| rhs & 1 != 0) {
+                    for (shared, other) in [(x, y), (y, x)] {
+                        if (z == shared && w == self.not(other)) || (w == shared && z == self.not(other)) {
+                            return self.not(shared);
+                        }
+                    }
+                }
+            }
+        }
+
+        self.and(a, b)
+    }
+
+    fn get_aiger_header(&self, format: &str) -> String {
+        let max_var: usize = self.inputs.len() + self.ands.len();
+
+        format!("{} {} {} 0 1 {}\n", format, max_var, self.inputs.len(), self.ands.len())
+    }
+
+    fn get_aiger_symbols(&self) -> String {
+        self.inputs
+            .iter()
+            .enumerate()
+            .map(|(i, c)| format!("i{} {}\n", i, c))
+            .collect()
+    }
+}
+
+fn get_value(values: &[bool], lit: Literal) -> bool {
+    values[(lit / 2) as usize] != (lit & 1 != 0)
+}
+
+fn get_vars(curr_node: RcNode, vars: &mut BTreeSet<char>) {
+    if let Some(ref node) = *curr_node.borrow() {
+        if let Symbols::Char(c @ 'A'..='Z') = node.data {
+            vars.insert(c);
+        }
+        get_vars(Rc::clone(&node.left), vars);
+        get_vars(Rc::clone(&node.right), vars);
+    }
+}
+
+fn encode_delta(res: &mut Vec<u8>, mut delta: Literal) {
+    while delta >= 0x80 {
+        res.push((delta & 0x7f) as u8 | 0x80);
+        delta >>= 7;
+    }
+    res.push(delta as u8);
+}
+
+fn decode_delta(content: &[u8], pos: &mut usize) -> Literal {
+    let mut res: Literal = 0;
+    let mut shift: u32 = 0;
+
+    loop {
+        let byte: u8 = *content.get(*pos).unwrap_or_else(|| {
+            panic!("Invalid AIGER file");
+        });
+
+        if shift > 28 {
+            panic!("Invalid AIGER file");
+        }
+        *pos += 1;
+        res |= ((byte & 0x7f) as Literal) << shift;
+        if byte & 0x80 == 0 {
+            return res;
+        }
+        shift += 7;
+    }
+}
+
+fn read_line<'a>(content: &'a [u8], pos: &mut usize) -> Option<&'a str> {
+    let rest: &[u8] = content.get(*pos..)?;
+    let len: usize = rest.iter().position(|c: &u8| *c == b'\n')?;
+
+    *pos += len + 1;
+    std::str::from_utf8(&rest[..len]).ok()
+}
+
+/// Parse the header "aag M I L O A" and return (M, I, A)
+fn parse_aiger_header(line: Option<&str>, format: &str) -> (Literal, usize, usize) {
+    let fields: Vec<&str> = line.unwrap_or_default().split_whitespace().collect();
+
+    if fields.len() != 6 || fields[0] != format {
+        panic!("Invalid AIGER file");
+    }
+    let values: Vec<usize> = fields[1..]
+        .iter()
+        .map(|field: &&str| field.parse().unwrap_or_else(|_| panic!("Invalid AIGER file")))
+        .collect();
+
+    // no latch and a single output, the inputs and the AND nodes fit in the variables
+    if values[2] != 0 || values[3] != 1 || values[0] < values[1] + values[4] || values[0] >= 1 << 31 {
+        panic!("Invalid AIGER file");
+    }
+
+    (values[0] as Literal, values[1], values[4])
+}
+
+fn parse_literals(line: Option<&str>, count: usize) -> Vec<Literal> {
+    let literals: Vec<Literal> = line
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(|field: &str| field.parse().unwrap_or_else(|_| panic!("Invalid AIGER file")))
+        .collect();
+
+    if literals.len() != count {
+        panic!("Invalid AIGER file");
+    }
+
+    literals
+}
+
+/// Get the names of the inputs from the symbol table, which ends at the comment section
+fn parse_aiger_symbols<'a>(lines: impl Iterator<Item = &'a str>, input_cnt: usize) -> Vec<char> {
+    if input_cnt > 26 {
+        panic!("Too many inputs");
+    }
+    let mut names: Vec<char> = (0..input_cnt).map(|i: usize| (b'A' + i as u8) as char).collect();
+
+    for line in lines.take_while(|line: &&str| *line != "c") {
+        let Some((symbol, name)) = line.split_once(' ') else {
+            panic!("Invalid AIGER file");
+        };
+        let index: Option<usize> = symbol.strip_prefix('i').and_then(|i: &str| i.parse().ok());
+
+        if let (Some(index), Some(c @ 'A'..='Z'), 1) = (index, name.chars().next(), name.len()) {
+            if index >= input_cnt {
+                panic!("Invalid AIGER file");
+            }
+            names[index] = c;
+        }
+    }
+
+    names
+}
+
+/// Build the AIG from the definitions of the AND nodes (lhs => (rhs0, rhs1)) starting at the output
+fn import_aiger(
+    names: &[char],
+    max_var: Literal,
+    definitions: &HashMap<Literal, (Literal, Literal)>,
+    output: Literal,
+) -> Aig {
+    let mut res: Aig = Aig::new(names);
+    let mut memo: HashMap<Literal, Literal> = HashMap::new();
+    let mut visiting: BTreeSet<Literal> = BTreeSet::new();
+    let lit: Literal = import_node(output & !1, max_var, names.len(), definitions, &mut res, &mut memo, &mut visiting);
+
+    res.output = lit ^ (output & 1);
+    res
+}
+
+fn import_node(
+    lit: Literal,
+    max_var: Literal,
+    input_cnt: usize,
+    definitions: &HashMap<Literal, (Literal, Literal)>,
+    res: &mut Aig,
+    memo: &mut HashMap<Literal, Literal>,
+    visiting: &mut BTreeSet<Literal>,
+) -> Literal {
+    if lit / 2 > max_var {
+        panic!("Invalid AIGER file");
+    }
+    if lit / 2 <= input_cnt as Literal {
+        return lit;
+    }
+    if let Some(new_lit) = memo.get(&lit) {
+        return *new_lit;
+    }
+    // an undefined node or a node reading itself is not a valid combinational circuit
+    let Some((a, b)) = definitions.get(&lit).copied().filter(|_| visiting.insert(lit)) else {
+        panic!("Invalid AIGER file");
+    };
+    let new_a: Literal = import_node(a & !1, max_var, input_cnt, definitions, res, memo, visiting) ^ (a & 1);
+    let new_b: Literal = import_node(b & !1, max_var, input_cnt, definitions, res, memo, visiting) ^ (b & 1);
+    let new_lit: Literal = res.and(new_a, new_b);
+
+    memo.insert(lit, new_lit);
+    new_lit
+}
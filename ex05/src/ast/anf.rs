@@ -0,0 +1,209 @@
+use super::*;
+use std::collections::BTreeSet;
+
+/// The algebraic normal form (Zhegalkin polynomial) of a formula,
+/// which is a XOR of conjunctions of vars, e.g. 1 ^ A ^ (A & B)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Anf {
+    vars: Vec<char>,
+    monomials: Vec<usize>,
+}
+
+impl Anf {
+    /// Get the algebraic normal form of the formula held by the AST
+    /// # Arguments
+    /// * `tree` - The AST of the formula
+    /// # Panics
+    /// If the formula has more than 20 vars, because the truth vector holds 2 ^ vars values
+    pub fn new(tree: &AST) -> Self {
+        let mut unique_chars: BTreeSet<char> = BTreeSet::new();
+
+        get_vars(Rc::clone(&tree.root), &mut unique_chars);
+        if unique_chars.len() > 20 {
+            panic!("Too many vars to compute the algebraic normal form");
+        }
+        let vars: Vec<char> = unique_chars.into_iter().collect();
+        let mut truth_vector: Vec<bool> = vec![false; 1 << vars.len()];
+
+        /*
+            Fill the truth vector, the bit i of the index holds the value of the var i,
+            e.g. for the vars A, B the index 0b10 means A = 0 and B = 1.
+         */
+        for (mask, value) in truth_vector.iter_mut().enumerate() {
+            let mut assignment: [bool; 26] = [false; 26];
+
+            for (i, c) in vars.iter().enumerate() {
+                assignment[(*c as u8 - b'A') as usize] = mask & (1 << i) != 0;
+            }
+            *value = eval_assignment(Rc::clone(&tree.root), &assignment);
+        }
+        /*
+            Apply the Möbius transform on the truth vector, after it the index holds
+            the coefficient of the monomial made of the vars set in the index:
+            for each var i, every index with the bit i set is xored with the same
+            index without the bit i.
+            Example for A | B (truth vector 0, 1, 1, 1):
+                i = 0 (A): 0, 1, 1, 0
+                i = 1 (B): 0, 1, 1, 1  =>  A ^ B ^ (A & B)
+         */
+        for i in 0..vars.len() {
+            for mask in 0..truth_vector.len() {
+                if mask & (1 << i) != 0 {
+                    truth_vector[mask] ^= truth_vector[mask ^ (1 << i)];
+                }
+            }
+        }
+        let mut monomials: Vec<usize> = (0..truth_vector.len())
+            .filter(|mask: &usize| truth_vector[*mask])
+            .collect();
+
+        // Sort the monomials by degree first and then by the names of their vars
+        monomials.sort_by_key(|mask: &usize| (mask.count_ones(), get_monomial_vars(&vars, *mask)));
+
+        Self { vars, monomials }
+    }
+
+    /// Get the vars of the formula in alphabetical order
+    pub fn get_vars(&self) -> &Vec<char> {
+        &self.vars
+    }
+
+    /// Get the monomials of the polynomial, each monomial is the list of its vars,
+    /// the constant 1 is an empty list
+    pub fn get_monomials(&self) -> Vec<String> {
+        self.monomials
+            .iter()
+            .map(|mask: &usize| get_monomial_vars(&self.vars, *mask))
+            .collect()
+    }
+
+    /// Get the algebraic degree, which is the number of vars of the largest monomial
+    pub fn degree(&self) -> usize {
+        self.monomials
+            .iter()
+            .map(|mask: &usize| mask.count_ones() as usize)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Convert the polynomial back to an AST using only the Xor and And operators
+    pub fn to_ast(&self) -> AST {
+        let mut tree: AST = AST::new();
+        let mut root: Option<RcNode> = None;
+
+        /*
+            Build the tree as a chain of Xor nodes, each monomial being a chain of And nodes:
+                       ^
+                      / \
+                     ^   &
+                    / \ / \
+                   1  A A  B
+         */
+        for mask in self.monomials.iter() {
+            let monomial: RcNode = self.get_monomial_subtree(*mask);
+
+            root = Some(match root {
+                Some(lhs) => get_new_node(Symbols::Xor, lhs, monomial),
+                None => monomial,
+            });
+        }
+        tree.root = root.unwrap_or_else(|| get_leaf('0'));
+
+        tree
+    }
+
+    /// Get the RPN formula of the polynomial, e.g. "1A^AB&^"
+    pub fn get_rpn_formula(&self) -> String {
+        self.to_ast().get_rpn_formula()
+    }
+
+    fn get_monomial_subtree(&self, mask: usize) -> RcNode {
+        let mut res: Option<RcNode> = None;
+
+        for c in get_monomial_vars(&self.vars, mask).chars() {
+            res = Some(match res {
+                Some(lhs) => get_new_node(Symbols::And, lhs, get_leaf(c)),
+                None => get_leaf(c),
+            });
+        }
+
+        res.unwrap_or_else(|| get_leaf('1'))
+    }
+}
+
+fn get_monomial_vars(vars: &[char], mask: usize) -> String {
+    vars.iter()
+        .enumerate()
+        .filter(|(i, _)| mask & (1 << i) != 0)
+        .map(|(_, c)| *c)
+        .collect()
+}
+
+fn get_leaf(c: char) -> RcNode {
+    Rc::new(RefCell::new(Some(Box::new(Node::new(Symbols::Char(c))))))
+}
+
+fn get_new_node(data: Symbols, left: RcNode, right: RcNode) -> RcNode {
+    let new_node: RcNode = Rc::new(RefCell::new(Some(Box::new(Node::new(data)))));
+
+    new_node.borrow_mut().as_mut().unwrap().left = left;
+    new_node.borrow_mut().as_mut().unwrap().right = right;
+
+    new_node
+}
+
+fn get_vars(curr_node: RcNode, vars: &mut BTreeSet<char>) {
+    if let Some(ref node) = *curr_node.borrow() {
+        if let Symbols::Char(c @ 'A'..='Z') = node.data {
+            vars.insert(c);
+        }
+        get_vars(Rc::clone(&node.left), vars);
+        get_vars(Rc::clone(&node.right), vars);
+    }
+}
+
+fn eval_assignment(curr_node: RcNode, assignment: &[bool; 26]) -> bool {
+    let node = curr_node.borrow();
+    let node: &Node = node.as_ref().unwrap();
+
+    match node.data {
+        Symbols::And => {
+            eval_assignment(Rc::clone(&node.left), assignment)
+                & eval_assignment(Rc::clone(&node.right), assignment)
+        }
+        Symbols::Or => {
+            eval_assignment(Rc::clone(&node.left), assignment)
+                | eval_assignment(Rc::clone(&node.right), assignment)
+        }
+        Symbols::Xor => {
+            eval_assignment(Rc::clone(&node.left), assignment)
+                ^ eval_assignment(Rc::clone(&node.right), assignment)
+        }
+        Symbols::MatCond => {
+            !eval_assignment(Rc::clone(&node.left), assignment)
+                | eval_assignment(Rc::clone(&node.right), assignment)
+        }
+        Symbols::LogEq => {
+            eval_assignment(Rc::clone(&node.left), assignment)
+                == eval_assignment(Rc::clone(&node.right), assignment)
+        }
+        Symbols::Nand => {
+            !(eval_assignment(Rc::clone(&node.left), assignment)
+                & eval_assignment(Rc::clone(&node.right), assignment))
+        }
+        Symbols::Nor => {
+            !(eval_assignment(Rc::clone(&node.left), assignment)
+                | eval_assignment(Rc::clone(&node.right), assignment))
+        }
+        Symbols::Xnor => {
+            eval_assignment(Rc::clone(&node.left), assignment)
+                == eval_assignment(Rc::clone(&node.right), assignment)
+        }
+        Symbols::Not => !eval_assignment(Rc::clone(&node.right), assignment),
+        Symbols::Char(c) => match c {
+            '1' => true,
+            '0' => false,
+            _ => assignment[(c as u8 - b'A') as usize],
+        },
+    }
+}
@@ -0,0 +1,86 @@
+use super::*;
+
+/// The universal gate used to rewrite a formula
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gate {
+    /// A ↑ B <=> !(A & B)
+    Nand,
+    /// A ↓ B <=> !(A | B)
+    Nor,
+}
+
+/// Rewrite the subtree using only the given gate and return its new root
+/// # Arguments
+/// * `curr_node` - The root of the current subtree, which should only hold the NOT, AND and OR operators
+/// * `negate` - A boolean to check if we want the negation of the subtree
+/// * `gate` - The gate used to rewrite the subtree
+pub fn rewrite_with_gate(curr_node: RcNode, negate: bool, gate: Gate) -> RcNode {
+    /*
+        In this function, we will rewrite the subtree from the root to the leaves,
+        the negations are pushed down to the children to avoid building !!A:
+        1. A char is kept as is, or rewritten as (A ↑ A) or (A ↓ A) if it should be negated
+        2. A not node flips the negation of its child
+        3. An AND or OR node is rewritten as follows:
+            NAND gate:   A & B <=> !(A ↑ B)      A | B <=> !A ↑ !B
+            NOR gate:    A | B <=> !(A ↓ B)      A & B <=> !A ↓ !B
+        when the operator matches the gate (AND with NAND, OR with NOR) the gate already
+        holds the negation of the node, otherwise we negate the children.
+     */
+    let (data, left, right) = {
+        let node = curr_node.borrow();
+        let node: &Node = node.as_ref().unwrap();
+
+        (node.data.clone(), Rc::clone(&node.left), Rc::clone(&node.right))
+    };
+
+    match data {
+        Symbols::Char(c) => {
+            let leaf: RcNode = Rc::new(RefCell::new(Some(Box::new(Node::new(Symbols::Char(c))))));
+
+            if negate {
+                get_negation(leaf, gate)
+            } else {
+                leaf
+            }
+        }
+        Symbols::Not => rewrite_with_gate(right, !negate, gate),
+        Symbols::And | Symbols::Or => {
+            let inverting: bool = matches!(
+                (&data, gate),
+                (Symbols::And, Gate::Nand) | (Symbols::Or, Gate::Nor)
+            );
+            let res: RcNode = get_gate_node(
+                gate,
+                rewrite_with_gate(left, !inverting, gate),
+                rewrite_with_gate(right, !inverting, gate),
+            );
+
+            if negate == inverting {
+                res
+            } else {
+                get_negation(res, gate)
+            }
+        }
+        _ => panic!("Invalid formula"),
+    }
+}
+
+fn get_gate_node(gate: Gate, left: RcNode, right: RcNode) -> RcNode {
+    let data: Symbols = match gate {
+        Gate::Nand => Symbols::Nand,
+        Gate::Nor => Symbols::Nor,
+    };
+    let new_node: RcNode = Rc::new(RefCell::new(Some(Box::new(Node::new(data)))));
+
+    new_node.borrow_mut().as_mut().unwrap().left = left;
+    new_node.borrow_mut().as_mut().unwrap().right = right;
+
+    new_node
+}
+
+/// Negate the subtree using the gate: !A <=> A ↑ A <=> A ↓ A
+fn get_negation(curr_node: RcNode, gate: Gate) -> RcNode {
+    let copy: RcNode = nnf::clone_subtree(Rc::clone(&curr_node));
+
+    get_gate_node(gate, curr_node, copy)
+}
@@ -1,6 +1,10 @@
 use super::*;
+use super::trace::Trace;
 
-fn clone_subtree(curr_node: RcNode) -> RcNode {
+/// Get a deep copy of the current subtree
+/// # Arguments
+/// * `curr_node` - The root of the subtree to copy
+pub fn clone_subtree(curr_node: RcNode) -> RcNode {
 
     match curr_node.borrow().as_ref() {
         Some(ref node) => {
@@ -72,10 +76,66 @@ fn get_equivalence_right_subtree(root: &mut Box<Node>) -> RcNode {
     mat_node
 }
 
+/// Rewrite the NAND, NOR and XNOR operators using the basic operators
+/// # Arguments
+/// * `curr_node` - The root of the AST
+/// * `trace` - The trace in which the rule applications are recorded
+pub fn rewrite_functional_operators(curr_node: RcNode, trace: &mut Trace) {
+    /*
+       In this function, we will rewrite the NAND, NOR and XNOR operators following these rules:
+       A ↑ B <=> !(A & B)
+       A ↓ B <=> !(A | B)
+       A ⊙ B <=> (A <=> B)
+       The algorithm is as follows:
+       1. Iterate through the AST
+       2. If the current node is a NAND (or NOR) operator, change it to a NOT operator
+       and move its children under a new AND (or OR) node
+       3. If the current node is a XNOR operator, change it to an equivalence operator
+           +---------------------+       +--------------------+
+           |    nand subtree     |       |    rewrite rule    |
+           +---------------------+       +--------------------+
+                    ↑                             !
+                   / \                             \
+                  /   \            --->              &
+                 /     \                           /   \
+                A       B                         A     B
+    */
+    if let Some(ref mut node) = curr_node.borrow_mut().as_mut() {
+        match node.data {
+            Symbols::Nand | Symbols::Nor => {
+                let matched: Option<String> = trace.snapshot(node);
+                let symbol: Symbols = if let Symbols::Nand = node.data {
+                    Symbols::And
+                } else {
+                    Symbols::Or
+                };
+                let new_node: RcNode = Rc::new(RefCell::new(Some(Box::new(Node::new(symbol)))));
+
+                new_node.borrow_mut().as_mut().unwrap().left = Rc::clone(&node.left);
+                new_node.borrow_mut().as_mut().unwrap().right = Rc::clone(&node.right);
+                node.data = Symbols::Not;
+                node.left = Rc::new(RefCell::new(None));
+                node.right = new_node;
+                trace.record("Rewrite functional operator", matched, node);
+            }
+            Symbols::Xnor => {
+                let matched: Option<String> = trace.snapshot(node);
+
+                node.data = Symbols::LogEq;
+                trace.record("Rewrite functional operator", matched, node);
+            }
+            _ => {}
+        }
+        rewrite_functional_operators(Rc::clone(&node.left), trace);
+        rewrite_functional_operators(Rc::clone(&node.right), trace);
+    }
+}
+
 /// Rewrite the equivalence operator
 /// # Arguments
 /// * `curr_node` - The root of the AST
-pub fn rewrite_equivalence(curr_node: RcNode) {
+/// * `trace` - The trace in which the rule applications are recorded
+pub fn rewrite_equivalence(curr_node: RcNode, trace: &mut Trace) {
     /*
        In this function, we will rewrite the equivalence operator following this rule:
        (A <=> B) <=> (A => B) & (B => A)
@@ -99,14 +159,16 @@ pub fn rewrite_equivalence(curr_node: RcNode) {
     match curr_node.borrow_mut().as_mut() {
         Some(ref mut node) => {
             if let Symbols::LogEq = node.data {
+                let matched: Option<String> = trace.snapshot(node);
                 let tmp_left: RcNode = get_equivalence_left_subtree(node);
 
                 node.data = Symbols::And;
                 node.right = get_equivalence_right_subtree(node);
                 node.left = Rc::clone(&tmp_left);
+                trace.record("Rewrite equivalence", matched, node);
             }
-            rewrite_equivalence(Rc::clone(&node.left));
-            rewrite_equivalence(Rc::clone(&node.right));
+            rewrite_equivalence(Rc::clone(&node.left), trace);
+            rewrite_equivalence(Rc::clone(&node.right), trace);
         }
         None => {}
     }
@@ -115,7 +177,8 @@ pub fn rewrite_equivalence(curr_node: RcNode) {
 /// Rewrite the implication operator
 /// # Arguments
 /// * `curr_node` - The root of the AST
-pub fn rewrite_material_conditions(curr_node: RcNode) {
+/// * `trace` - The trace in which the rule applications are recorded
+pub fn rewrite_material_conditions(curr_node: RcNode, trace: &mut Trace) {
     /*
        In this function, we will rewrite the material condition operator following this rule:
        (A => B) <=> !A | B
@@ -135,15 +198,17 @@ pub fn rewrite_material_conditions(curr_node: RcNode) {
     match curr_node.borrow_mut().as_mut() {
         Some(ref mut node) => {
             if let Symbols::MatCond = node.data {
+                let matched: Option<String> = trace.snapshot(node);
                 let not_node: RcNode =
                     Rc::new(RefCell::new(Some(Box::new(Node::new(Symbols::Not)))));
 
                 node.data = Symbols::Or;
                 not_node.borrow_mut().as_mut().unwrap().right = Rc::clone(&node.left);
                 node.left = Rc::clone(&not_node);
+                trace.record("Rewrite material condition", matched, node);
             }
-            rewrite_material_conditions(Rc::clone(&node.left));
-            rewrite_material_conditions(Rc::clone(&node.right));
+            rewrite_material_conditions(Rc::clone(&node.left), trace);
+            rewrite_material_conditions(Rc::clone(&node.right), trace);
         }
         None => {}
     }
@@ -210,7 +275,8 @@ fn get_xor_right_subtree(curr_node: &mut Box<Node>) -> RcNode {
 /// Rewrite the XOR operator
 /// # Arguments
 /// * `curr_node` - The root of the AST
-pub fn rewrite_xor_operator(curr_node: RcNode) {
+/// * `trace` - The trace in which the rule applications are recorded
+pub fn rewrite_xor_operator(curr_node: RcNode, trace: &mut Trace) {
     /*
        In this function, we will rewrite the XOR operator following this rule:
        A ^ B <=> (A & !B) | (B & !A)
@@ -233,14 +299,16 @@ pub fn rewrite_xor_operator(curr_node: RcNode) {
     match curr_node.borrow_mut().as_mut() {
         Some(ref mut node) => {
             if let Symbols::Xor = node.data {
+                let matched: Option<String> = trace.snapshot(node);
                 let tmp_left: RcNode = get_xor_left_subtree(node);
 
                 node.data = Symbols::Or;
                 node.right = get_xor_right_subtree(node);
                 node.left = Rc::clone(&tmp_left);
+                trace.record("Rewrite xor operator", matched, node);
             }
-            rewrite_xor_operator(Rc::clone(&node.right));
-            rewrite_xor_operator(Rc::clone(&node.left));
+            rewrite_xor_operator(Rc::clone(&node.right), trace);
+            rewrite_xor_operator(Rc::clone(&node.left), trace);
         }
         None => {}
     }
@@ -250,7 +318,8 @@ pub fn rewrite_xor_operator(curr_node: RcNode) {
 /// # Arguments
 /// * `curr_node` - The root of the current subtree
 /// * `not_oper_cnt` - The number of NOT operators
-pub fn eliminate_double_negation(curr_node: RcNode, not_oper_cnt: usize) -> RcNode {
+/// * `trace` - The trace in which the rule applications are recorded
+pub fn eliminate_double_negation(curr_node: RcNode, not_oper_cnt: usize, trace: &mut Trace) -> RcNode {
     /*
        In this function, we will eliminate the double negation following this rule:
        !!A <=> A
@@ -262,8 +331,17 @@ pub fn eliminate_double_negation(curr_node: RcNode, not_oper_cnt: usize) -> RcNo
     */
     match curr_node.borrow_mut().as_mut() {
         Some(ref mut node) => match node.data {
-            Symbols::Not => eliminate_double_negation(Rc::clone(&node.right), not_oper_cnt + 1),
+            Symbols::Not => eliminate_double_negation(Rc::clone(&node.right), not_oper_cnt + 1, trace),
             _ => {
+                if not_oper_cnt > 1 && trace.is_enabled() {
+                    let rpn: String = get_node_rpn(node);
+
+                    trace.record_rpn(
+                        "Eliminate double negation",
+                        format!("{}{}", rpn, "!".repeat(not_oper_cnt)),
+                        format!("{}{}", rpn, "!".repeat(not_oper_cnt % 2)),
+                    );
+                }
                 if not_oper_cnt % 2 == 0 {
                     return Rc::clone(&curr_node);
                 } else {
@@ -281,7 +359,8 @@ pub fn eliminate_double_negation(curr_node: RcNode, not_oper_cnt: usize) -> RcNo
 /// Remove double negations from the AST
 /// # Arguments
 /// * `curr_node` - The root of the AST
-pub fn remove_double_negations(curr_node: RcNode) {
+/// * `trace` - The trace in which the rule applications are recorded
+pub fn remove_double_negations(curr_node: RcNode, trace: &mut Trace) {
     /*
        Call the eliminate_double_negation function for each node in the AST
     */
@@ -293,10 +372,10 @@ pub fn remove_double_negations(curr_node: RcNode) {
     }
     match curr_node.borrow_mut().as_mut() {
         Some(ref mut node) => {
-            node.right = eliminate_double_negation(Rc::clone(&node.right), 0);
-            node.left = eliminate_double_negation(Rc::clone(&node.left), 0);
-            remove_double_negations(Rc::clone(&node.left));
-            remove_double_negations(Rc::clone(&node.right));
+            node.right = eliminate_double_negation(Rc::clone(&node.right), 0, trace);
+            node.left = eliminate_double_negation(Rc::clone(&node.left), 0, trace);
+            remove_double_negations(Rc::clone(&node.left), trace);
+            remove_double_negations(Rc::clone(&node.right), trace);
         }
         None => {}
     }
@@ -351,7 +430,8 @@ pub fn remove_not_node(curr_node: RcNode, found_not: bool) -> (RcNode, bool) {
 /// # Arguments
 /// * `curr_node` - The root of the AST
 /// * `found_not` - A boolean indicating if a NOT operator was found
-pub fn morgan_law(curr_node: RcNode, mut found_not: bool) {
+/// * `trace` - The trace in which the rule applications are recorded
+pub fn morgan_law(curr_node: RcNode, mut found_not: bool, trace: &mut Trace) {
     /*
        In this function, we will apply De Morgan's laws to the AST
        The algorithm is as follows:
@@ -364,11 +444,30 @@ pub fn morgan_law(curr_node: RcNode, mut found_not: bool) {
     match curr_node.borrow_mut().as_mut() {
         Some(ref mut node) => {
             if found_not {
+                let matched: Option<String> = trace.snapshot(node);
+
                 match node.data {
                     Symbols::And => node.data = Symbols::Or,
                     Symbols::Or => node.data = Symbols::And,
                     _ => {}
                 }
+                /*
+                    At this point the children still hold the negations that will be removed
+                    by remove_not_node, so the result is recorded with the negation pushed down
+                    to each child: !(A & B) => !A | !B
+                 */
+                if let Some(matched) = matched {
+                    trace.record_rpn(
+                        "Apply De Morgan's law",
+                        format!("{}!", matched),
+                        format!(
+                            "{}!{}!{}",
+                            get_rpn_formula(Rc::clone(&node.left)),
+                            get_rpn_formula(Rc::clone(&node.right)),
+                            get_symbol(&node.data)
+                        ),
+                    );
+                }
             }
             let tmp: bool = found_not;
             if let Symbols::Not = node.right.borrow().as_ref().unwrap().data {
@@ -378,7 +477,7 @@ pub fn morgan_law(curr_node: RcNode, mut found_not: bool) {
 
             node.right = right_subtree;
             if call_subtree {
-                morgan_law(Rc::clone(&node.right), found_not);
+                morgan_law(Rc::clone(&node.right), found_not, trace);
             }
 
             found_not = tmp;
@@ -389,7 +488,7 @@ pub fn morgan_law(curr_node: RcNode, mut found_not: bool) {
 
             node.left = left_subtree;
             if call_subtree {
-                morgan_law(Rc::clone(&node.left), found_not);
+                morgan_law(Rc::clone(&node.left), found_not, trace);
             }
         }
         None => {}
@@ -400,22 +499,39 @@ pub fn morgan_law(curr_node: RcNode, mut found_not: bool) {
 /// # Arguments
 /// * `curr_node` - The root of the AST
 pub fn get_rpn_formula(curr_node: RcNode) -> String {
-    let mut res: String = String::new();
-
     match curr_node.borrow().as_ref() {
-        Some(ref node) => {
-            res += &get_rpn_formula(Rc::clone(&node.left));
-            res += &get_rpn_formula(Rc::clone(&node.right));
-            match node.data {
-                Symbols::And => res += "&",
-                Symbols::Or => res += "|",
-                Symbols::Not => res += "!",
-                Symbols::Char(c) => res += &c.to_string(),
-                _ => {}
-            };
-
-            res
-        }
+        Some(ref node) => get_node_rpn(node),
         None => "".to_string(),
     }
-}
\ No newline at end of file
+}
+
+/// Get the RPN formula of the subtree rooted at the given node
+/// # Arguments
+/// * `node` - The root of the subtree
+pub fn get_node_rpn(node: &Node) -> String {
+    let mut res: String = String::new();
+
+    res += &get_rpn_formula(Rc::clone(&node.left));
+    res += &get_rpn_formula(Rc::clone(&node.right));
+    res += &get_symbol(&node.data);
+
+    res
+}
+
+/// Get the RPN symbol of the given token
+/// # Arguments
+/// * `data` - The token of the node
+fn get_symbol(data: &Symbols) -> String {
+    match data {
+        Symbols::And => "&".to_string(),
+        Symbols::Or => "|".to_string(),
+        Symbols::Not => "!".to_string(),
+        Symbols::Xor => "^".to_string(),
+        Symbols::MatCond => ">".to_string(),
+        Symbols::LogEq => "=".to_string(),
+        Symbols::Nand => "↑".to_string(),
+        Symbols::Nor => "↓".to_string(),
+        Symbols::Xnor => "⊙".to_string(),
+        Symbols::Char(c) => c.to_string(),
+    }
+}
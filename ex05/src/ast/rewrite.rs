@@ -0,0 +1,286 @@
+use super::*;
+use std::collections::{HashMap, HashSet};
+
+/// The order in which the tree is searched for a subtree matching a rule
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Strategy {
+    /// Rewrite the deepest matching subtrees first
+    Innermost,
+    /// Rewrite the matching subtrees closest to the root first
+    Outermost,
+}
+
+/// A rewrite rule written as `lhs => rhs` using the RPN notation of the formulas,
+/// each variable of the pattern matches any subtree while the constants '1' and '0'
+/// only match themselves, for example the De Morgan's law !(A & B) => !A | !B
+/// is written as "AB&! => A!B!|"
+#[derive(Debug)]
+pub struct Rule {
+    name: String,
+    lhs: RcNode,
+    rhs: RcNode,
+}
+
+impl Rule {
+    /// Get a new Rule instance
+    /// # Arguments
+    /// * `rule` - A string slice that holds the rule, e.g. "AB> => A!B|"
+    /// # Panics
+    /// If the rule is invalid or if the right side uses a variable missing from the left side
+    pub fn new(rule: &str) -> Self {
+        let (lhs, rhs) = rule.split_once(" => ").unwrap_or_else(|| {
+            panic!("Invalid rewrite rule");
+        });
+        let lhs: RcNode = parse_pattern(lhs.trim());
+        let rhs: RcNode = parse_pattern(rhs.trim());
+        let mut lhs_vars: HashSet<char> = HashSet::new();
+        let mut rhs_vars: HashSet<char> = HashSet::new();
+
+        get_pattern_vars(Rc::clone(&lhs), &mut lhs_vars);
+        get_pattern_vars(Rc::clone(&rhs), &mut rhs_vars);
+        if !rhs_vars.is_subset(&lhs_vars) {
+            panic!("Invalid rewrite rule");
+        }
+
+        Self {
+            name: rule.trim().to_string(),
+            lhs,
+            rhs,
+        }
+    }
+
+    /// Get the name of the rule, which is the rule itself
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// A set of rewrite rules applied until no rule matches the tree anymore
+#[derive(Debug)]
+pub struct RewriteSystem {
+    rules: Vec<Rule>,
+    strategy: Strategy,
+    max_steps: usize,
+}
+
+impl RewriteSystem {
+    /// Get a new RewriteSystem instance without any rule
+    /// # Arguments
+    /// * `strategy` - The order in which the tree is searched for a matching subtree
+    pub fn new(strategy: Strategy) -> Self {
+        Self {
+            rules: Vec::new(),
+            strategy,
+            max_steps: 10_000,
+        }
+    }
+
+    /// Get the rules that rewrite a formula into its negation normal form
+    /// # Example
+    /// ```
+    /// use ex05::{ast::rewrite::RewriteSystem, rewrite_formula};
+    ///
+    /// assert_eq!("A!B|B!A|&", rewrite_formula("AB=", &RewriteSystem::nnf()));
+    /// ```
+    pub fn nnf() -> Self {
+        let mut system: RewriteSystem = RewriteSystem::new(Strategy::Outermost);
+
+        // (A <=> B) <=> (A => B) & (B => A)
+        system.add_rule("AB= => AB>BA>&");
+        // (A => B) <=> !A | B
+        system.add_rule("AB> => A!B|");
+        // A ^ B <=> (A & !B) | (B & !A)
+        system.add_rule("AB^ => AB!&BA!&|");
+        // !!A <=> A
+        system.add_rule("A!! => A");
+        // !(A & B) <=> !A | !B
+        system.add_rule("AB&! => A!B!|");
+        // !(A | B) <=> !A & !B
+        system.add_rule("AB|! => A!B!&");
+
+        system
+    }
+
+    /// Add a rule to the system, the rules are tried in the order they were added
+    /// # Arguments
+    /// * `rule` - A string slice that holds the rule, e.g. "AB> => A!B|"
+    pub fn add_rule(&mut self, rule: &str) {
+        self.rules.push(Rule::new(rule));
+    }
+
+    /// Get the rules of the system
+    pub fn get_rules(&self) -> &Vec<Rule> {
+        &self.rules
+    }
+
+    /// Set the maximum number of rules applied before giving up
+    /// # Arguments
+    /// * `max_steps` - The maximum number of rule applications
+    pub fn set_max_steps(&mut self, max_steps: usize) {
+        self.max_steps = max_steps;
+    }
+
+    /// Apply the rules on the tree until no rule matches and return the number of applied rules
+    /// # Arguments
+    /// * `root` - The root of the AST
+    /// * `trace` - The trace in which the rule applications are recorded
+    /// # Panics
+    /// If the maximum number of steps is reached or if the rules rewrite the tree
+    /// into a formula that was already seen
+    pub fn rewrite(&self, root: RcNode, trace: &mut Trace) -> usize {
+        let mut steps: usize = 0;
+        let mut seen: HashSet<String> = HashSet::new();
+
+        /*
+            Each step rewrites one subtree and then starts the search again from the root,
+            we stop when no rule matches anymore (the fixpoint).
+            Two guards make sure that we terminate:
+            1- the number of steps should not exceed max_steps (e.g. A => AA& never stops growing)
+            2- the formula should never come back to a previous state (e.g. AB& => BA&)
+         */
+        seen.insert(nnf::get_rpn_formula(Rc::clone(&root)));
+        loop {
+            let applied: bool = match self.strategy {
+                Strategy::Innermost => self.rewrite_innermost(Rc::clone(&root), trace),
+                Strategy::Outermost => self.rewrite_outermost(Rc::clone(&root), trace),
+            };
+
+            if !applied {
+                return steps;
+            }
+            steps += 1;
+            if steps > self.max_steps {
+                panic!("Rewrite step limit exceeded");
+            }
+            if !seen.insert(nnf::get_rpn_formula(Rc::clone(&root))) {
+                panic!("Rewrite rules do not terminate");
+            }
+        }
+    }
+
+    fn rewrite_outermost(&self, curr_node: RcNode, trace: &mut Trace) -> bool {
+        if curr_node.borrow().is_none() {
+            return false;
+        }
+        if self.apply_rules(Rc::clone(&curr_node), trace) {
+            return true;
+        }
+        let (left, right) = get_children(&curr_node);
+
+        self.rewrite_outermost(left, trace) || self.rewrite_outermost(right, trace)
+    }
+
+    fn rewrite_innermost(&self, curr_node: RcNode, trace: &mut Trace) -> bool {
+        if curr_node.borrow().is_none() {
+            return false;
+        }
+        let (left, right) = get_children(&curr_node);
+
+        self.rewrite_innermost(left, trace)
+            || self.rewrite_innermost(right, trace)
+            || self.apply_rules(Rc::clone(&curr_node), trace)
+    }
+
+    fn apply_rules(&self, curr_node: RcNode, trace: &mut Trace) -> bool {
+        for rule in self.rules.iter() {
+            let mut bindings: HashMap<char, RcNode> = HashMap::new();
+
+            if match_pattern(Rc::clone(&rule.lhs), Rc::clone(&curr_node), &mut bindings) {
+                let matched: Option<String> = trace.snapshot(curr_node.borrow().as_ref().unwrap());
+                let res: RcNode = instantiate_pattern(Rc::clone(&rule.rhs), &bindings);
+
+                /*
+                    Replace the content of the current node with the new subtree, this way
+                    the parent (or the root of the AST) keeps pointing to the same RcNode.
+                 */
+                let new_node: Option<Box<Node>> = res.borrow_mut().take();
+                *curr_node.borrow_mut() = new_node;
+                trace.record(&rule.name, matched, curr_node.borrow().as_ref().unwrap());
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+fn get_children(curr_node: &RcNode) -> (RcNode, RcNode) {
+    let node = curr_node.borrow();
+    let node: &Node = node.as_ref().unwrap();
+
+    (Rc::clone(&node.left), Rc::clone(&node.right))
+}
+
+fn parse_pattern(pattern: &str) -> RcNode {
+    /*
+        A pattern made of a single variable is not a valid formula for the parser,
+        so we build the leaf ourselves, e.g. the right side of "A!! => A".
+     */
+    if pattern.len() == 1 && matches!(pattern.as_bytes()[0], b'A'..=b'Z' | b'0' | b'1') {
+        let c: char = pattern.as_bytes()[0] as char;
+
+        return Rc::new(RefCell::new(Some(Box::new(Node::new(Symbols::Char(c))))));
+    }
+    let mut tree: AST = AST::new();
+
+    tree.allow_constants();
+    tree.build(pattern, true);
+    tree.root
+}
+
+fn get_pattern_vars(curr_node: RcNode, vars: &mut HashSet<char>) {
+    if let Some(ref node) = *curr_node.borrow() {
+        if let Symbols::Char(c @ 'A'..='Z') = node.data {
+            vars.insert(c);
+        }
+        get_pattern_vars(Rc::clone(&node.left), vars);
+        get_pattern_vars(Rc::clone(&node.right), vars);
+    }
+}
+
+fn match_pattern(pattern: RcNode, curr_node: RcNode, bindings: &mut HashMap<char, RcNode>) -> bool {
+    /*
+        Match the pattern against the current subtree, the algorithm is as follows:
+        1- if the pattern is a variable, bind it to the current subtree, if the variable
+        is already bound, the current subtree should be equal to the bound one,
+        e.g. the pattern "AA&" matches "BC|BC|&" but not "BC|CB|&".
+        2- otherwise the current node should hold the same operator as the pattern
+        and its children should match the children of the pattern.
+     */
+    match (pattern.borrow().as_ref(), curr_node.borrow().as_ref()) {
+        (None, None) => true,
+        (Some(pattern_node), Some(node)) => {
+            if let Symbols::Char(c @ 'A'..='Z') = pattern_node.data {
+                if let Some(bound) = bindings.get(&c) {
+                    return nnf::get_rpn_formula(Rc::clone(bound)) == nnf::get_node_rpn(node);
+                }
+                bindings.insert(c, Rc::clone(&curr_node));
+                return true;
+            }
+            pattern_node.data == node.data
+                && match_pattern(Rc::clone(&pattern_node.left), Rc::clone(&node.left), bindings)
+                && match_pattern(Rc::clone(&pattern_node.right), Rc::clone(&node.right), bindings)
+        }
+        _ => false,
+    }
+}
+
+fn instantiate_pattern(pattern: RcNode, bindings: &HashMap<char, RcNode>) -> RcNode {
+    match pattern.borrow().as_ref() {
+        Some(pattern_node) => {
+            if let Symbols::Char(c @ 'A'..='Z') = pattern_node.data {
+                return nnf::clone_subtree(Rc::clone(bindings.get(&c).unwrap()));
+            }
+            let new_node: RcNode =
+                Rc::new(RefCell::new(Some(Box::new(Node::new(pattern_node.data.clone())))));
+
+            new_node.borrow_mut().as_mut().unwrap().left =
+                instantiate_pattern(Rc::clone(&pattern_node.left), bindings);
+            new_node.borrow_mut().as_mut().unwrap().right =
+                instantiate_pattern(Rc::clone(&pattern_node.right), bindings);
+
+            new_node
+        }
+        None => Rc::new(RefCell::new(None)),
+    }
+}
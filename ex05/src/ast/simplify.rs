@@ -0,0 +1,275 @@
+use super::*;
+
+/// Simplify the current subtree and return its new root
+/// # Arguments
+/// * `curr_node` - The root of the current subtree
+/// * `trace` - The trace in which the rule applications are recorded
+pub fn simplify(curr_node: RcNode, trace: &mut Trace) -> RcNode {
+    /*
+        In this function, we will simplify the tree from the leaves to the root,
+        the algorithm is as follows:
+        1. Simplify the left and right subtrees
+        2. Build a new node with the current operator and the simplified subtrees
+        3. Apply the first matching law on the new node, and keep applying the laws
+        on the result until none of them matches, each law returns a smaller subtree
+        so this will always stop.
+     */
+    let (data, left, right) = match curr_node.borrow().as_ref() {
+        Some(node) => (node.data.clone(), Rc::clone(&node.left), Rc::clone(&node.right)),
+        None => return Rc::new(RefCell::new(None)),
+    };
+
+    if let Symbols::Char(_) = data {
+        return curr_node;
+    }
+    let mut res: RcNode = get_new_node(
+        data,
+        simplify(left, trace),
+        simplify(right, trace),
+    );
+
+    while let Some((rule, simplified)) = apply_laws(&res) {
+        if trace.is_enabled() {
+            trace.record_rpn(
+                rule,
+                nnf::get_rpn_formula(Rc::clone(&res)),
+                nnf::get_rpn_formula(Rc::clone(&simplified)),
+            );
+        }
+        res = simplified;
+    }
+
+    res
+}
+
+fn apply_laws(curr_node: &RcNode) -> Option<(&'static str, RcNode)> {
+    let (data, left, right) = match curr_node.borrow().as_ref() {
+        Some(node) => (node.data.clone(), Rc::clone(&node.left), Rc::clone(&node.right)),
+        None => return None,
+    };
+
+    match data {
+        Symbols::Not => {
+            // !1 <=> 0, !0 <=> 1
+            if let Some(value) = get_constant(&right) {
+                return Some(("Constant propagation", get_constant_node(!value)));
+            }
+            // !!A <=> A
+            if let Some(Symbols::Not) = get_data(&right) {
+                return Some(("Double negation", get_children(&right).1));
+            }
+            None
+        }
+        Symbols::And => {
+            // A & 0 <=> 0
+            if get_constant(&left) == Some(false) || get_constant(&right) == Some(false) {
+                return Some(("Annihilation", get_constant_node(false)));
+            }
+            // A & 1 <=> A
+            if get_constant(&left) == Some(true) {
+                return Some(("Identity", right));
+            }
+            if get_constant(&right) == Some(true) {
+                return Some(("Identity", left));
+            }
+            // A & A <=> A
+            if is_equal(&left, &right) {
+                return Some(("Idempotence", left));
+            }
+            // A & !A <=> 0
+            if is_complement(&left, &right) {
+                return Some(("Complementation", get_constant_node(false)));
+            }
+            // A & (A | B) <=> A
+            if is_absorbed(&left, &right, Symbols::Or) {
+                return Some(("Absorption", left));
+            }
+            if is_absorbed(&right, &left, Symbols::Or) {
+                return Some(("Absorption", right));
+            }
+            None
+        }
+        Symbols::Or => {
+            // A | 1 <=> 1
+            if get_constant(&left) == Some(true) || get_constant(&right) == Some(true) {
+                return Some(("Annihilation", get_constant_node(true)));
+            }
+            // A | 0 <=> A
+            if get_constant(&left) == Some(false) {
+                return Some(("Identity", right));
+            }
+            if get_constant(&right) == Some(false) {
+                return Some(("Identity", left));
+            }
+            // A | A <=> A
+            if is_equal(&left, &right) {
+                return Some(("Idempotence", left));
+            }
+            // A | !A <=> 1
+            if is_complement(&left, &right) {
+                return Some(("Complementation", get_constant_node(true)));
+            }
+            // A | (A & B) <=> A
+            if is_absorbed(&left, &right, Symbols::And) {
+                return Some(("Absorption", left));
+            }
+            if is_absorbed(&right, &left, Symbols::And) {
+                return Some(("Absorption", right));
+            }
+            None
+        }
+        Symbols::Xor => {
+            // A ^ 0 <=> A, A ^ 1 <=> !A
+            if let Some(value) = get_constant(&left) {
+                return Some(("Constant propagation", get_negation(right, value)));
+            }
+            if let Some(value) = get_constant(&right) {
+                return Some(("Constant propagation", get_negation(left, value)));
+            }
+            // A ^ A <=> 0
+            if is_equal(&left, &right) {
+                return Some(("Nilpotence", get_constant_node(false)));
+            }
+            // A ^ !A <=> 1
+            if is_complement(&left, &right) {
+                return Some(("Complementation", get_constant_node(true)));
+            }
+            None
+        }
+        Symbols::MatCond => {
+            // 0 => A <=> 1, 1 => A <=> A
+            if let Some(value) = get_constant(&left) {
+                let res: RcNode = if value { right } else { get_constant_node(true) };
+
+                return Some(("Constant propagation", res));
+            }
+            // A => 1 <=> 1, A => 0 <=> !A
+            if let Some(value) = get_constant(&right) {
+                let res: RcNode = if value { get_constant_node(true) } else { get_negation(left, true) };
+
+                return Some(("Constant propagation", res));
+            }
+            // A => A <=> 1
+            if is_equal(&left, &right) {
+                return Some(("Reflexivity", get_constant_node(true)));
+            }
+            None
+        }
+        Symbols::Nand | Symbols::Nor => {
+            // A ↑ 0 <=> 1, A ↑ 1 <=> !A, A ↓ 1 <=> 0, A ↓ 0 <=> !A
+            let absorbing: bool = data == Symbols::Nor;
+
+            for (constant, other) in [(&left, &right), (&right, &left)] {
+                if let Some(value) = get_constant(constant) {
+                    let res: RcNode = if value == absorbing {
+                        get_constant_node(!absorbing)
+                    } else {
+                        get_negation(Rc::clone(other), true)
+                    };
+
+                    return Some(("Constant propagation", res));
+                }
+            }
+            None
+        }
+        Symbols::LogEq | Symbols::Xnor => {
+            // A <=> 1 <=> A, A <=> 0 <=> !A
+            if let Some(value) = get_constant(&left) {
+                return Some(("Constant propagation", get_negation(right, !value)));
+            }
+            if let Some(value) = get_constant(&right) {
+                return Some(("Constant propagation", get_negation(left, !value)));
+            }
+            // A <=> A <=> 1
+            if is_equal(&left, &right) {
+                return Some(("Reflexivity", get_constant_node(true)));
+            }
+            // A <=> !A <=> 0
+            if is_complement(&left, &right) {
+                return Some(("Complementation", get_constant_node(false)));
+            }
+            None
+        }
+        Symbols::Char(_) => None,
+    }
+}
+
+fn get_new_node(data: Symbols, left: RcNode, right: RcNode) -> RcNode {
+    let new_node: RcNode = Rc::new(RefCell::new(Some(Box::new(Node::new(data)))));
+
+    new_node.borrow_mut().as_mut().unwrap().left = left;
+    new_node.borrow_mut().as_mut().unwrap().right = right;
+
+    new_node
+}
+
+fn get_constant_node(value: bool) -> RcNode {
+    let c: char = if value { '1' } else { '0' };
+
+    Rc::new(RefCell::new(Some(Box::new(Node::new(Symbols::Char(c))))))
+}
+
+/// Negate the subtree if `negate` is true, otherwise return it as is
+fn get_negation(curr_node: RcNode, negate: bool) -> RcNode {
+    if !negate {
+        return curr_node;
+    }
+
+    get_new_node(Symbols::Not, Rc::new(RefCell::new(None)), curr_node)
+}
+
+fn get_data(curr_node: &RcNode) -> Option<Symbols> {
+    curr_node.borrow().as_ref().map(|node| node.data.clone())
+}
+
+fn get_children(curr_node: &RcNode) -> (RcNode, RcNode) {
+    let node = curr_node.borrow();
+    let node: &Node = node.as_ref().unwrap();
+
+    (Rc::clone(&node.left), Rc::clone(&node.right))
+}
+
+fn get_constant(curr_node: &RcNode) -> Option<bool> {
+    match get_data(curr_node) {
+        Some(Symbols::Char('1')) => Some(true),
+        Some(Symbols::Char('0')) => Some(false),
+        _ => None,
+    }
+}
+
+fn is_equal(lhs: &RcNode, rhs: &RcNode) -> bool {
+    nnf::get_rpn_formula(Rc::clone(lhs)) == nnf::get_rpn_formula(Rc::clone(rhs))
+}
+
+fn is_complement(lhs: &RcNode, rhs: &RcNode) -> bool {
+    /*
+        Two subtrees are the complement of each other if one of them
+        is the negation of the other: A and !A
+     */
+    if let Some(Symbols::Not) = get_data(lhs) {
+        if is_equal(&get_children(lhs).1, rhs) {
+            return true;
+        }
+    }
+    if let Some(Symbols::Not) = get_data(rhs) {
+        if is_equal(lhs, &get_children(rhs).1) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn is_absorbed(lhs: &RcNode, rhs: &RcNode, symbol: Symbols) -> bool {
+    /*
+        Check if the right subtree is absorbed by the left one, which means that
+        the right subtree holds the given operator and one of its children is equal
+        to the left subtree: A & (A | B), A | (A & B)
+     */
+    if get_data(rhs) != Some(symbol) {
+        return false;
+    }
+    let (left, right) = get_children(rhs);
+
+    is_equal(lhs, &left) || is_equal(lhs, &right)
+}
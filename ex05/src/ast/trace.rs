@@ -0,0 +1,139 @@
+use super::*;
+
+/// A single rule application recorded while rewriting the AST
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceStep {
+    /// The name of the applied rule
+    pub rule: String,
+    /// The RPN formula of the subtree matched by the rule
+    pub matched: String,
+    /// The RPN formula of the subtree after applying the rule
+    pub result: String,
+}
+
+/// The list of the rules applied while rewriting the AST
+#[derive(Debug, Clone, Default)]
+pub struct Trace {
+    enabled: bool,
+    steps: Vec<TraceStep>,
+}
+
+impl Trace {
+    /// Get a new Trace instance
+    /// # Arguments
+    /// * `enabled` - A boolean to check if the rule applications should be recorded
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Check if the rule applications are recorded
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Get the recorded steps in the order they were applied
+    pub fn get_steps(&self) -> &Vec<TraceStep> {
+        &self.steps
+    }
+
+    /// Get the RPN formula of the subtree before applying a rule on it,
+    /// returns None if the trace is disabled to avoid building the formula for nothing
+    /// # Arguments
+    /// * `node` - The root of the subtree that will be rewritten
+    pub fn snapshot(&self, node: &Node) -> Option<String> {
+        if !self.enabled {
+            return None;
+        }
+
+        Some(nnf::get_node_rpn(node))
+    }
+
+    /// Record the application of a rule on the subtree rooted at the given node
+    /// # Arguments
+    /// * `rule` - The name of the applied rule
+    /// * `matched` - The snapshot of the subtree taken before applying the rule
+    /// * `node` - The root of the subtree after applying the rule
+    pub fn record(&mut self, rule: &str, matched: Option<String>, node: &Node) {
+        if let Some(matched) = matched {
+            self.record_rpn(rule, matched, nnf::get_node_rpn(node));
+        }
+    }
+
+    /// Record the application of a rule from the RPN formulas of the subtree
+    /// # Arguments
+    /// * `rule` - The name of the applied rule
+    /// * `matched` - The RPN formula of the subtree before applying the rule
+    /// * `result` - The RPN formula of the subtree after applying the rule
+    pub fn record_rpn(&mut self, rule: &str, matched: String, result: String) {
+        if self.enabled {
+            self.steps.push(TraceStep {
+                rule: rule.to_string(),
+                matched,
+                result,
+            });
+        }
+    }
+
+    /// Export the trace as text, one step per line
+    /// # Example
+    /// ```
+    /// use ex05::trace_negation_normal_form;
+    ///
+    /// let (_, trace) = trace_negation_normal_form("AB>");
+    /// assert_eq!("1. Rewrite material condition: AB> => A!B|\n", trace.to_text());
+    /// ```
+    pub fn to_text(&self) -> String {
+        let mut res: String = String::new();
+
+        for (i, step) in self.steps.iter().enumerate() {
+            res += &format!("{}. {}: {} => {}\n", i + 1, step.rule, step.matched, step.result);
+        }
+
+        res
+    }
+
+    /// Export the trace as a JSON array of steps
+    /// # Example
+    /// ```
+    /// use ex05::trace_negation_normal_form;
+    ///
+    /// let (_, trace) = trace_negation_normal_form("AB>");
+    /// assert_eq!(
+    ///     r#"[{"rule":"Rewrite material condition","matched":"AB>","result":"A!B|"}]"#,
+    ///     trace.to_json()
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        let steps: Vec<String> = self
+            .steps
+            .iter()
+            .map(|step: &TraceStep| {
+                format!(
+                    "{{\"rule\":\"{}\",\"matched\":\"{}\",\"result\":\"{}\"}}",
+                    escape_json(&step.rule),
+                    escape_json(&step.matched),
+                    escape_json(&step.result)
+                )
+            })
+            .collect();
+
+        format!("[{}]", steps.join(","))
+    }
+}
+
+fn escape_json(s: &str) -> String {
+    let mut res: String = String::new();
+
+    for c in s.chars() {
+        match c {
+            '"' => res += "\\\"",
+            '\\' => res += "\\\\",
+            _ => res.push(c),
+        }
+    }
+
+    res
+}
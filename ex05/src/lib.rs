@@ -1,5 +1,8 @@
 pub mod ast;
 
+use ast::gates::Gate;
+use ast::rewrite::RewriteSystem;
+use ast::trace::Trace;
 use ast::AST;
 
 pub fn negation_normal_form(formula: &str) -> String {
@@ -13,9 +16,207 @@ pub fn negation_normal_form(formula: &str) -> String {
     tree.get_rpn_formula()
 }
 
+/// Get the negation normal form along with the trace of the applied rules
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// # Example
+/// ```
+/// use ex05::trace_negation_normal_form;
+///
+/// let (nnf, trace) = trace_negation_normal_form("AB=");
+/// assert_eq!("A!B|B!A|&", nnf);
+/// print!("{}", trace.to_text());
+/// // Output:
+/// // 1. Rewrite equivalence: AB= => AB>BA>&
+/// // 2. Rewrite material condition: AB> => A!B|
+/// // 3. Rewrite material condition: BA> => B!A|
+/// ```
+pub fn trace_negation_normal_form(formula: &str) -> (String, Trace) {
+    if formula.is_empty() {
+        panic!("Invalid formula");
+    }
+    let mut tree: AST = AST::new();
+
+    tree.build(formula, true);
+    tree.enable_trace();
+    tree.simplify_material_properties();
+    (tree.get_rpn_formula(), tree.get_trace().clone())
+}
+
+/// Rewrite a formula using the rules of the given rewrite system
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// * `system` - The rewrite system holding the rules and the strategy to apply them
+/// # Example
+/// ```
+/// use ex05::ast::rewrite::{RewriteSystem, Strategy};
+/// use ex05::rewrite_formula;
+///
+/// let mut system: RewriteSystem = RewriteSystem::new(Strategy::Innermost);
+///
+/// // absorption: A | (A & B) <=> A
+/// system.add_rule("AAB&| => A");
+/// assert_eq!("CD|", rewrite_formula("CD|CD|E&|", &system));
+/// ```
+pub fn rewrite_formula(formula: &str, system: &RewriteSystem) -> String {
+    if formula.is_empty() {
+        panic!("Invalid formula");
+    }
+    let mut tree: AST = AST::new();
+
+    tree.build(formula, true);
+    tree.rewrite(system);
+    tree.get_rpn_formula()
+}
+
+/// Simplify a formula mixing vars and the constants '1' and '0' using the algebraic laws
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// # Example
+/// ```
+/// use ex05::simplify_formula;
+///
+/// assert_eq!("A", simplify_formula("A1&"));
+/// assert_eq!("0", simplify_formula("AA!&"));
+/// assert_eq!("A", simplify_formula("AAB&|"));
+/// ```
+pub fn simplify_formula(formula: &str) -> String {
+    if formula.is_empty() {
+        panic!("Invalid formula");
+    }
+    let mut tree: AST = AST::new();
+
+    tree.allow_constants();
+    tree.build(formula, true);
+    tree.simplify();
+    tree.get_rpn_formula()
+}
+
+/// Rewrite a formula using only the NAND operator '↑'
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// # Example
+/// ```
+/// use ex05::nand_form;
+///
+/// assert_eq!("AA↑", nand_form("A!"));
+/// assert_eq!("AB↑AB↑↑", nand_form("AB&"));
+/// assert_eq!("AA↑BB↑↑", nand_form("AB|"));
+/// ```
+pub fn nand_form(formula: &str) -> String {
+    gate_form(formula, Gate::Nand)
+}
+
+/// Rewrite a formula using only the NOR operator '↓'
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// # Example
+/// ```
+/// use ex05::nor_form;
+///
+/// assert_eq!("AA↓", nor_form("A!"));
+/// assert_eq!("AA↓BB↓↓", nor_form("AB&"));
+/// assert_eq!("AB↓AB↓↓", nor_form("AB|"));
+/// ```
+pub fn nor_form(formula: &str) -> String {
+    gate_form(formula, Gate::Nor)
+}
+
+fn gate_form(formula: &str, gate: Gate) -> String {
+    if formula.is_empty() {
+        panic!("Invalid formula");
+    }
+    let mut tree: AST = AST::new();
+
+    tree.build(formula, true);
+    tree.rewrite_with_gate(gate);
+    tree.get_rpn_formula()
+}
+
+/// Get the algebraic normal form (Zhegalkin polynomial) of a formula mixing vars
+/// and the constants '1' and '0', which is a XOR of conjunctions of vars
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// # Example
+/// ```
+/// use ex05::algebraic_normal_form;
+///
+/// assert_eq!("AB^AB&^", algebraic_normal_form("AB|"));
+/// assert_eq!("1A^", algebraic_normal_form("A!"));
+/// assert_eq!("0", algebraic_normal_form("AA^"));
+/// ```
+pub fn algebraic_normal_form(formula: &str) -> String {
+    if formula.is_empty() {
+        panic!("Invalid formula");
+    }
+    let mut tree: AST = AST::new();
+
+    tree.allow_constants();
+    tree.build(formula, true);
+    tree.get_anf().get_rpn_formula()
+}
+
+/// Synthesize a formula mixing vars and the constants '1' and '0' into a structural Verilog module,
+/// see netlist::verilog_netlist
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// * `module` - The name of the module
+/// # Panics
+/// If the formula is invalid
+pub fn verilog_netlist(formula: &str, module: &str) -> String {
+    netlist::verilog_netlist(formula, module)
+}
+
+/// Synthesize a formula mixing vars and the constants '1' and '0' into a BLIF model, see netlist::blif_netlist
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// * `model` - The name of the model
+/// # Panics
+/// If the formula is invalid
+pub fn blif_netlist(formula: &str, model: &str) -> String {
+    netlist::blif_netlist(formula, model)
+}
+
+/// Get the And-Inverter Graph of a formula mixing vars and the constants '1' and '0'
+/// in the ASCII AIGER format, after the rewriting and balancing passes
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// # Example
+/// ```
+/// use ex05::aiger_formula;
+///
+/// print!("{}", aiger_formula("AB|C!&"));
+/// // Output:
+/// // aag 5 3 0 1 2
+/// // 2
+/// // 4
+/// // 6
+/// // 10
+/// // 8 5 3
+/// // 10 9 7
+/// // i0 A
+/// // i1 B
+/// // i2 C
+/// ```
+pub fn aiger_formula(formula: &str) -> String {
+    if formula.is_empty() {
+        panic!("Invalid formula");
+    }
+    let mut tree: AST = AST::new();
+
+    tree.allow_constants();
+    tree.build(formula, true);
+    let mut aig: ast::aig::Aig = tree.get_aig();
+
+    aig.rewrite();
+    aig.balance();
+    aig.to_aag()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ast::rewrite::Strategy;
 
     #[test]
     fn subject_tests() {
@@ -43,6 +244,296 @@ mod tests {
         assert_eq!("A!B!|C!|", negation_normal_form("AB&!C!|"));
     }
 
+    #[test]
+    fn trace_tests() {
+        let (nnf, trace) = trace_negation_normal_form("AB=");
+        assert_eq!("A!B|B!A|&", nnf);
+        assert_eq!(
+            "1. Rewrite equivalence: AB= => AB>BA>&\n\
+             2. Rewrite material condition: AB> => A!B|\n\
+             3. Rewrite material condition: BA> => B!A|\n",
+            trace.to_text()
+        );
+
+        let (nnf, trace) = trace_negation_normal_form("AB|C&!");
+        assert_eq!("A!B!&C!|", nnf);
+        assert_eq!(
+            vec!["Apply De Morgan's law", "Apply De Morgan's law"],
+            trace.get_steps().iter().map(|step| step.rule.as_str()).collect::<Vec<&str>>()
+        );
+        assert_eq!("AB|C&!", trace.get_steps()[0].matched);
+        assert_eq!("AB|!C!|", trace.get_steps()[0].result);
+        assert_eq!("AB|!", trace.get_steps()[1].matched);
+        assert_eq!("A!B!&", trace.get_steps()[1].result);
+
+        let (nnf, trace) = trace_negation_normal_form("A!B>");
+        assert_eq!("AB|", nnf);
+        assert_eq!(
+            "[{\"rule\":\"Rewrite material condition\",\"matched\":\"A!B>\",\"result\":\"A!!B|\"},\
+             {\"rule\":\"Eliminate double negation\",\"matched\":\"A!!\",\"result\":\"A\"}]",
+            trace.to_json()
+        );
+
+        let (nnf, trace) = trace_negation_normal_form("AB&");
+        assert_eq!("AB&", nnf);
+        assert!(trace.get_steps().is_empty());
+        assert_eq!("", trace.to_text());
+        assert_eq!("[]", trace.to_json());
+    }
+
+    #[test]
+    fn nnf_rewrite_system_tests() {
+        let system: RewriteSystem = RewriteSystem::nnf();
+
+        for formula in [
+            "AB&!", "AB|!", "AB>", "AB=", "AB|C&!", "A!!", "A!B&", "AB&!CD&|!", "EF=MN==",
+            "ABC&!>", "A!B>", "A!B>!", "ACK&!|!", "AB^", "AB^!", "AB^C>", "AB&!C!|",
+        ] {
+            assert_eq!(negation_normal_form(formula), rewrite_formula(formula, &system));
+        }
+    }
+
+    #[test]
+    fn custom_rewrite_rules_tests() {
+        let mut system: RewriteSystem = RewriteSystem::new(Strategy::Innermost);
+
+        // idempotence and absorption
+        system.add_rule("AA& => A");
+        system.add_rule("AA| => A");
+        system.add_rule("AAB&| => A");
+        assert_eq!("AB|", rewrite_formula("AB|AB|&", &system));
+        assert_eq!("C", rewrite_formula("CCD&|", &system));
+        assert_eq!("CD&", rewrite_formula("CD&CD&|CD&E!&|", &system));
+        // a variable used twice should match equal subtrees only
+        assert_eq!("AB|BA|&", rewrite_formula("AB|BA|&", &system));
+        assert_eq!(3, system.get_rules().len());
+        assert_eq!("AAB&| => A", system.get_rules()[2].get_name());
+    }
+
+    #[test]
+    fn rewrite_strategy_tests() {
+        let mut outermost: RewriteSystem = RewriteSystem::new(Strategy::Outermost);
+        let mut innermost: RewriteSystem = RewriteSystem::new(Strategy::Innermost);
+        let mut tree: AST = AST::new();
+
+        outermost.add_rule("AB> => A!B|");
+        innermost.add_rule("AB> => A!B|");
+
+        tree.build("AB>C>", true);
+        tree.enable_trace();
+        assert_eq!(2, tree.rewrite(&outermost));
+        assert_eq!("AB>C>", tree.get_trace().get_steps()[0].matched);
+        assert_eq!("AB>!C|", tree.get_trace().get_steps()[0].result);
+        assert_eq!("A!B|!C|", tree.get_rpn_formula());
+
+        tree = AST::new();
+        tree.build("AB>C>", true);
+        tree.enable_trace();
+        assert_eq!(2, tree.rewrite(&innermost));
+        assert_eq!("AB>", tree.get_trace().get_steps()[0].matched);
+        assert_eq!("A!B|", tree.get_trace().get_steps()[0].result);
+        assert_eq!("A!B|!C|", tree.get_rpn_formula());
+    }
+
+    #[test]
+    #[should_panic(expected = "Rewrite rules do not terminate")]
+    fn rewrite_rules_cycle_test() {
+        let mut system: RewriteSystem = RewriteSystem::new(Strategy::Outermost);
+
+        system.add_rule("AB& => BA&");
+        rewrite_formula("AB&", &system);
+    }
+
+    #[test]
+    #[should_panic(expected = "Rewrite step limit exceeded")]
+    fn rewrite_rules_step_limit_test() {
+        let mut system: RewriteSystem = RewriteSystem::new(Strategy::Innermost);
+
+        system.add_rule("AB| => AB|A|");
+        system.set_max_steps(50);
+        rewrite_formula("AB|", &system);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid rewrite rule")]
+    fn invalid_rewrite_rule_unbound_var_test() {
+        let mut system: RewriteSystem = RewriteSystem::new(Strategy::Outermost);
+
+        system.add_rule("AB& => AC&");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid rewrite rule")]
+    fn invalid_rewrite_rule_missing_arrow_test() {
+        let mut system: RewriteSystem = RewriteSystem::new(Strategy::Outermost);
+
+        system.add_rule("AB&");
+    }
+
+    #[test]
+    fn functional_operators_tests() {
+        assert_eq!("A!B!|", negation_normal_form("AB↑"));
+        assert_eq!("A!B!&", negation_normal_form("AB↓"));
+        assert_eq!("A!B|B!A|&", negation_normal_form("AB⊙"));
+        assert_eq!("AB&", negation_normal_form("AB↑!"));
+        assert_eq!("AB|C!|", negation_normal_form("AB↓C&!"));
+        assert_eq!("AB&C|", negation_normal_form("AB↑!C↓!"));
+        assert_eq!("1AB&^", algebraic_normal_form("AB↑"));
+        assert_eq!("1A^B^AB&^", algebraic_normal_form("AB↓"));
+        assert_eq!("1A^B^", algebraic_normal_form("AB⊙"));
+        assert_eq!("A!", simplify_formula("A1↑"));
+        assert_eq!("1", simplify_formula("A0↑"));
+        assert_eq!("0", simplify_formula("1A↓"));
+        assert_eq!("A", simplify_formula("A1⊙"));
+    }
+
+    #[test]
+    fn gate_form_tests() {
+        let formulas: [&str; 10] = [
+            "AB&!", "AB|C&", "AB>", "AB=", "AB^", "AB↑C↓", "AB⊙C!|", "A!!B|C^", "ABC&&D>E=", "AB&!CD&|!",
+        ];
+
+        for formula in formulas {
+            for (gate, symbol) in [(Gate::Nand, '↑'), (Gate::Nor, '↓')] {
+                let mut tree: AST = AST::new();
+
+                tree.build(formula, true);
+                let anf = tree.get_anf();
+                tree.rewrite_with_gate(gate);
+
+                // the rewritten tree has the same truth table as the original formula
+                assert_eq!(anf, tree.get_anf());
+                assert!(tree
+                    .get_rpn_formula()
+                    .chars()
+                    .all(|c: char| c.is_ascii_uppercase() || c == symbol));
+            }
+        }
+        assert_eq!("AB↑", nand_form("AB&!"));
+        assert_eq!("AB↑", nand_form("A!B!|"));
+        assert_eq!("AB↓", nor_form("AB|!"));
+        assert_eq!("AB↓", nor_form("A!B!&"));
+        assert_eq!("AB↑", nand_form("AB↑"));
+        assert_eq!("AB↓", nor_form("AB↓"));
+    }
+
+    #[test]
+    fn anf_tests() {
+        assert_eq!("AB&", algebraic_normal_form("AB&"));
+        assert_eq!("AB^", algebraic_normal_form("AB^"));
+        assert_eq!("AB^AB&^", algebraic_normal_form("AB|"));
+        assert_eq!("1A^AB&^", algebraic_normal_form("AB>"));
+        assert_eq!("1A^B^", algebraic_normal_form("AB="));
+        assert_eq!("1AB&^", algebraic_normal_form("AB&!"));
+        assert_eq!("A", algebraic_normal_form("A1&"));
+        assert_eq!("1", algebraic_normal_form("A!A|"));
+        assert_eq!("0", algebraic_normal_form("A0&"));
+        assert_eq!("AC&BC&^", algebraic_normal_form("AB^C&"));
+        // majority function
+        assert_eq!("AB&AC&^BC&^", algebraic_normal_form("AB&AC&|BC&|"));
+    }
+
+    #[test]
+    fn anf_degree_and_ast_tests() {
+        let formulas: [&str; 6] = ["AB|", "AB>C^", "AB&C&!", "AB=C|D&", "A!B!&C|", "AB^CD^="];
+
+        for formula in formulas {
+            let mut tree: AST = AST::new();
+
+            tree.build(formula, true);
+            let anf = tree.get_anf();
+            let mut back: AST = anf.to_ast();
+
+            // the back-converted tree has the same truth table as the original formula
+            assert_eq!(anf, back.get_anf());
+            back.simplify();
+            assert_eq!(anf, back.get_anf());
+        }
+
+        let mut tree: AST = AST::new();
+
+        tree.build("AB&C&!", true);
+        let anf = tree.get_anf();
+        assert_eq!(3, anf.degree());
+        assert_eq!(&vec!['A', 'B', 'C'], anf.get_vars());
+        assert_eq!(vec!["".to_string(), "ABC".to_string()], anf.get_monomials());
+        tree.build("AB^C^", true);
+        assert_eq!(1, tree.get_anf().degree());
+        tree.build("AA!&", true);
+        assert_eq!(0, tree.get_anf().degree());
+    }
+
+    #[test]
+    fn simplify_tests() {
+        // identity and annihilation
+        assert_eq!("A", simplify_formula("A1&"));
+        assert_eq!("A", simplify_formula("0A|"));
+        assert_eq!("0", simplify_formula("A0&"));
+        assert_eq!("1", simplify_formula("1A|"));
+        // idempotence and complementation
+        assert_eq!("A", simplify_formula("AA|"));
+        assert_eq!("AB|", simplify_formula("AB|AB|&"));
+        assert_eq!("0", simplify_formula("AA!&"));
+        assert_eq!("1", simplify_formula("A!A|"));
+        // absorption
+        assert_eq!("A", simplify_formula("AAB&|"));
+        assert_eq!("A", simplify_formula("AB|A&"));
+        assert_eq!("C!", simplify_formula("C!BC!&|"));
+        // constant propagation
+        assert_eq!("0", simplify_formula("10&"));
+        assert_eq!("1", simplify_formula("1!0!|1&!!"));
+        assert_eq!("A!", simplify_formula("A1^"));
+        assert_eq!("A", simplify_formula("A!1^"));
+        assert_eq!("A!", simplify_formula("A0>"));
+        assert_eq!("B", simplify_formula("1B>"));
+        assert_eq!("A!", simplify_formula("0A="));
+        assert_eq!("1", simplify_formula("AB&AB&="));
+        assert_eq!("AB&", simplify_formula("A0|B1&&"));
+        // nothing to simplify
+        assert_eq!("AB&C|", simplify_formula("AB&C|"));
+        assert_eq!("AB^", simplify_formula("AB^"));
+    }
+
+    #[test]
+    fn simplify_trace_tests() {
+        let mut tree: AST = AST::new();
+
+        tree.build("AA!&B|", true);
+        tree.enable_trace();
+        tree.simplify();
+        assert_eq!("B", tree.get_rpn_formula());
+        assert_eq!(
+            "1. Complementation: AA!& => 0\n2. Identity: 0B| => B\n",
+            tree.get_trace().to_text()
+        );
+    }
+
+    #[test]
+    fn rewrite_rules_with_constants_tests() {
+        let mut system: RewriteSystem = RewriteSystem::new(Strategy::Innermost);
+        let mut tree: AST = AST::new();
+
+        system.add_rule("A1& => A");
+        system.add_rule("A0& => 0");
+        tree.allow_constants();
+        tree.build("B1&C0&|", true);
+        tree.rewrite(&system);
+        assert_eq!("B0|", tree.get_rpn_formula());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid formula")]
+    fn invalid_mixed_formula_test() {
+        simplify_formula("A2&");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid formula")]
+    fn invalid_formula_constant_without_option_test() {
+        // only the entry points calling allow_constants accept the mixed formulas
+        negation_normal_form("A1&");
+    }
+
     #[test]
     #[should_panic(expected = "Invalid formula")]
     fn invalid_formula_empty_string_test() {
@@ -120,4 +611,164 @@ mod tests {
     fn invalid_formula_unknown_symbol_test4() {
         negation_normal_form("111|");
     }
+
+    fn check_aig(aig: &ast::aig::Aig, formula: &str) {
+        let mut tree: AST = AST::new();
+
+        tree.allow_constants();
+        tree.build(formula, true);
+        let inputs: &Vec<char> = aig.get_inputs();
+        let monomials: Vec<String> = tree.get_anf().get_monomials();
+
+        for mask in 0..(1 << inputs.len()) {
+            let values: Vec<bool> = (0..inputs.len()).map(|i: usize| mask & (1 << i) != 0).collect();
+            let expected: bool = monomials.iter().fold(false, |acc: bool, monomial: &String| {
+                acc ^ monomial.chars().all(|c: char| values[inputs.binary_search(&c).unwrap()])
+            });
+
+            assert_eq!(expected, aig.eval(&values), "{} {:?}", formula, values);
+        }
+    }
+
+    fn get_aig(formula: &str) -> ast::aig::Aig {
+        let mut tree: AST = AST::new();
+
+        tree.allow_constants();
+        tree.build(formula, true);
+        tree.get_aig()
+    }
+
+    #[test]
+    fn aig_hashing_tests() {
+        // A & B and B & A are the same node, and (A & B) | (A & B) is A & B
+        let aig = get_aig("AB&BA&|");
+        assert_eq!(1, aig.and_count());
+        assert_eq!(6, aig.get_output());
+
+        // the constants are propagated: (A & 0) | B <=> B
+        let aig = get_aig("A0&B|");
+        assert_eq!(0, aig.and_count());
+        assert_eq!(aig.get_input('B'), aig.get_output());
+        assert_eq!(1, get_aig("AA!|").get_output());
+        assert_eq!(0, get_aig("AB^AB^!&").get_output());
+
+        let mut aig = ast::aig::Aig::new(&['A', 'B']);
+        let (a, b) = (aig.get_input('A'), aig.get_input('B'));
+        let lhs = aig.and(a, b);
+        let rhs = aig.and(b, a);
+        assert_eq!(lhs, rhs);
+        assert_eq!(a, aig.and(a, 1));
+        assert_eq!(0, aig.and(a, aig.not(a)));
+    }
+
+    #[test]
+    fn aig_eval_tests() {
+        let formulas: [&str; 7] = ["AB&C|", "AB>C!AB>!&=", "AB↑C↓", "AB⊙CD^&", "AB|1&", "AB=C0|>", "ABCDE^^^^"];
+
+        for formula in formulas {
+            let mut aig = get_aig(formula);
+
+            check_aig(&aig, formula);
+            aig.rewrite();
+            check_aig(&aig, formula);
+            aig.balance();
+            check_aig(&aig, formula);
+            aig.cleanup();
+            check_aig(&aig, formula);
+            // the AST only uses the AND and NOT operators
+            let tree: AST = aig.to_ast();
+            check_aig(&tree.get_aig(), formula);
+            assert!(!tree.get_rpn_formula().contains(['|', '^', '>', '=', '↑', '↓', '⊙']));
+        }
+        // the operands are ordered by their literals
+        assert_eq!("C!AB&!&", get_aig("AB&!C!&").to_ast().get_rpn_formula());
+        assert_eq!("1", get_aig("AA!|").to_ast().get_rpn_formula());
+    }
+
+    #[test]
+    fn aig_passes_tests() {
+        let mut aig = get_aig("AB&C&D&E&F&G&H&");
+        assert_eq!(7, aig.depth());
+        aig.balance();
+        assert_eq!(3, aig.depth());
+        assert_eq!(7, aig.and_count());
+
+        // (A & B) & !A <=> 0
+        let mut aig = get_aig("AB&A!&C|");
+        assert_eq!(3, aig.and_count());
+        aig.rewrite();
+        assert_eq!(0, aig.and_count());
+        assert_eq!(aig.get_input('C'), aig.get_output());
+
+        // !(A & B) & !(A & !B) <=> !A
+        let mut aig = get_aig("AB&!AB!&!&");
+        assert_eq!(3, aig.and_count());
+        aig.rewrite();
+        assert_eq!(0, aig.and_count());
+        assert_eq!(aig.not(aig.get_input('A')), aig.get_output());
+
+        // !(A & B) & A <=> A & !B
+        let mut aig = get_aig("AB&!A&");
+        aig.rewrite();
+        assert_eq!(1, aig.and_count());
+        check_aig(&aig, "AB!&");
+
+        // the nodes which aren't used by the output anymore are removed
+        let mut aig = ast::aig::Aig::new(&['A', 'B', 'C']);
+        aig.and(aig.get_input('A'), aig.get_input('B'));
+        let bc = aig.and(aig.get_input('B'), aig.get_input('C'));
+        aig.set_output(aig.not(bc));
+        assert_eq!(2, aig.and_count());
+        aig.cleanup();
+        assert_eq!(1, aig.and_count());
+        assert_eq!(9, aig.get_output());
+    }
+
+    #[test]
+    fn aiger_tests() {
+        let aag: &str = "aag 5 3 0 1 2\n2\n4\n6\n11\n8 4 2\n10 9 7\ni0 A\ni1 B\ni2 C\n";
+        let aig = get_aig("AB&C|");
+        assert_eq!(aag, aig.to_aag());
+
+        let mut binary: Vec<u8> = b"aig 5 3 0 1 2\n11\n".to_vec();
+        binary.extend([4, 2, 1, 2]);
+        binary.extend(b"i0 A\ni1 B\ni2 C\n");
+        assert_eq!(binary, aig.to_aig());
+
+        assert_eq!(aag, ast::aig::Aig::from_aag(aag).to_aag());
+        assert_eq!(aag, ast::aig::Aig::from_aig(&binary).to_aag());
+
+        for formula in ["AB>C!AB>!&=", "ABCDEFGHIJ&&&&&&&&&", "AB⊙CD^&"] {
+            let aig = get_aig(formula);
+
+            check_aig(&ast::aig::Aig::from_aag(&aig.to_aag()), formula);
+            check_aig(&ast::aig::Aig::from_aig(&aig.to_aig()), formula);
+        }
+        // the nodes can be defined in any order, the inputs without symbol are named by their index
+        let aig = ast::aig::Aig::from_aag("aag 4 2 0 1 2\n2\n4\n9\n8 7 3\n6 5 3\nc\ncomment\n");
+        assert_eq!(&vec!['A', 'B'], aig.get_inputs());
+        check_aig(&aig, "AB!|");
+        // a delta above 127 takes several bytes
+        let mut aig = ast::aig::Aig::new(&['A', 'B', 'C']);
+        let mut lit = aig.and(aig.get_input('A'), aig.get_input('B'));
+        for _ in 0..100 {
+            lit = aig.and(lit, aig.get_input('C'));
+        }
+        aig.set_output(lit);
+        let binary: Vec<u8> = aig.to_aig();
+        assert_eq!(&[0x02, 0xC8, 0x01], &binary[binary.len() - 18..binary.len() - 15]);
+        assert_eq!(aig.to_aag(), ast::aig::Aig::from_aig(&binary).to_aag());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid AIGER file")]
+    fn aiger_latch_test() {
+        ast::aig::Aig::from_aag("aag 1 0 1 0 0\n2 3\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid AIGER file")]
+    fn aiger_cycle_test() {
+        ast::aig::Aig::from_aag("aag 3 1 0 1 2\n2\n4\n4 6 2\n6 4 2\n");
+    }
 }
@@ -1,9 +1,10 @@
+pub mod nary;
 pub mod nnf;
 
 use std::{cell::RefCell, fmt::Debug, rc::Rc};
 
 /// The possible tokens in the AST
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 enum Symbols {
     Char(char),
     Not,
@@ -12,6 +13,9 @@ enum Symbols {
     Xor,
     MatCond,
     LogEq,
+    Nand,
+    Nor,
+    Xnor,
 }
 
 type RcNode = Rc<RefCell<Option<Box<Node>>>>;
@@ -63,21 +67,22 @@ impl AST {
         let mut stack: Vec<char> = Vec::new();
         let mut is_oper: bool = false;
         let mut processed: usize = 0;
+        let formula_len: usize = formula.chars().count();
 
-        for c in formula.as_bytes() {
+        for c in formula.chars() {
             match c {
-                b'1' | b'0' if !allow_var => stack.push(*c as char),
-                b'A'..=b'Z' if allow_var => stack.push(*c as char),
-                b'|' => self.add_sub_tree(&mut stack, Symbols::Or),
-                b'&' => self.add_sub_tree(&mut stack, Symbols::And),
-                b'!' => {
+                '1' | '0' if !allow_var => stack.push(c),
+                'A'..='Z' if allow_var => stack.push(c),
+                '|' => self.add_sub_tree(&mut stack, Symbols::Or),
+                '&' => self.add_sub_tree(&mut stack, Symbols::And),
+                '!' => {
                     /*
                         if the previous character was an operator or if we are at the end of the
                         formula, we should add a new not node to the tree, otherwise we should
                         add the negation operator to the stack to use it again in the next iteration.
                      */
-                    if is_oper || (formula.len() - processed == 1) {
-                        self.add_not_node(&mut stack, formula.len() - processed == 1);
+                    if is_oper || (formula_len - processed == 1) {
+                        self.add_not_node(&mut stack, formula_len - processed == 1);
                     } else {
                         let top: char = stack.pop().unwrap_or_else(|| {
                             panic!("Invalid formula");
@@ -89,16 +94,19 @@ impl AST {
                          */
                         if top != '!' {
                             stack.push(top);
-                            stack.push(*c as char);
+                            stack.push(c);
                             self.not_cnt += 1;
                         } else {
                             self.not_cnt -= 1;
                         }
                     }
                 }
-                b'^' => self.add_sub_tree(&mut stack, Symbols::Xor),
-                b'>' => self.add_sub_tree(&mut stack, Symbols::MatCond),
-                b'=' => self.add_sub_tree(&mut stack, Symbols::LogEq),
+                '^' => self.add_sub_tree(&mut stack, Symbols::Xor),
+                '>' => self.add_sub_tree(&mut stack, Symbols::MatCond),
+                '=' => self.add_sub_tree(&mut stack, Symbols::LogEq),
+                '↑' => self.add_sub_tree(&mut stack, Symbols::Nand),
+                '↓' => self.add_sub_tree(&mut stack, Symbols::Nor),
+                '⊙' => self.add_sub_tree(&mut stack, Symbols::Xnor),
                 _ => panic!("Invalid formula"),
             }
             /*
@@ -114,13 +122,13 @@ impl AST {
                 check if should add it to stack if the top of the stack is not a negation operator.
              */
             if allow_var {
-                is_oper = if (*c < b'A' || *c > b'Z') && *c != b'!' {
+                is_oper = if !c.is_ascii_uppercase() && c != '!' {
                     true
                 } else {
                     false
                 };
             } else {
-                is_oper = if *c != b'1' && *c != b'0' && *c != b'!' {
+                is_oper = if c != '1' && c != '0' && c != '!' {
                     true
                 } else {
                     false
@@ -149,12 +157,18 @@ impl AST {
     }
 
     /// Simplify the material properties by applying the following rules:
-    /// 1. Rewrite the equivalence operator
-    /// 2. Rewrite the material conditions
-    /// 3. Rewrite the xor operator
-    /// 4. Eliminate double negation
-    /// 5. Apply the Morgan's law
+    /// 1. Rewrite the NAND, NOR and XNOR operators
+    /// 2. Rewrite the equivalence operator
+    /// 3. Rewrite the material conditions
+    /// 4. Rewrite the xor operator
+    /// 5. Eliminate double negation
+    /// 6. Apply the Morgan's law
     pub fn simplify_material_properties(&mut self) {
+        /*
+            Apply the Rewrite functional operators rule on the tree:
+            A ↑ B <=> !(A & B), A ↓ B <=> !(A | B), A ⊙ B <=> (A <=> B)
+         */
+        nnf::rewrite_functional_operators(Rc::clone(&self.root));
         /*
             Apply the Rewrite equivalence rule on the tree:
             (A <=> B) <=> (A => B) & (B => A)
@@ -206,6 +220,22 @@ impl AST {
         nnf::remove_double_negations(Rc::clone(&self.root));
     }
 
+    /// Get the n-ary tree of the AST, where the nested AND and OR nodes are merged
+    pub fn get_nary_tree(&self) -> nary::NaryNode {
+        let mut res: nary::NaryNode = nary::NaryNode::from_node(Rc::clone(&self.root));
+
+        res.flatten();
+        res
+    }
+
+    /// Get the n-ary tree of the AST, where only the nested AND nodes are merged
+    pub fn get_conjunction_tree(&self) -> nary::NaryNode {
+        let mut res: nary::NaryNode = nary::NaryNode::from_node(Rc::clone(&self.root));
+
+        res.flatten_conjunctions();
+        res
+    }
+
     /// Get the RPN formula using Post Order Traversal
     pub fn get_rpn_formula(&self) -> String {
         nnf::get_rpn_formula(Rc::clone(&self.root))
@@ -375,6 +405,18 @@ impl AST {
                 self.eval_tree(root.as_ref().unwrap().left.borrow().as_ref())
                     == self.eval_tree(root.as_ref().unwrap().right.borrow().as_ref())
             }
+            Symbols::Nand => {
+                !(self.eval_tree(root.as_ref().unwrap().left.borrow().as_ref())
+                    & self.eval_tree(root.as_ref().unwrap().right.borrow().as_ref()))
+            }
+            Symbols::Nor => {
+                !(self.eval_tree(root.as_ref().unwrap().left.borrow().as_ref())
+                    | self.eval_tree(root.as_ref().unwrap().right.borrow().as_ref()))
+            }
+            Symbols::Xnor => {
+                self.eval_tree(root.as_ref().unwrap().left.borrow().as_ref())
+                    == self.eval_tree(root.as_ref().unwrap().right.borrow().as_ref())
+            }
             Symbols::Not => {
                 !self.eval_tree(root.as_ref().unwrap().right.borrow().as_ref())
             },
@@ -0,0 +1,158 @@
+use super::*;
+use std::cmp::Ordering;
+
+/// A node of the n-ary tree, the AND and OR nodes can hold any number of children
+/// while the other operators keep their usual arity
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NaryNode {
+    data: Symbols,
+    children: Vec<NaryNode>,
+}
+
+impl NaryNode {
+    /// Get the n-ary tree of the subtree rooted at the given node,
+    /// each binary node is converted to a node with two children
+    /// # Arguments
+    /// * `curr_node` - The root of the subtree
+    pub(crate) fn from_node(curr_node: RcNode) -> Self {
+        let node = curr_node.borrow();
+        let node: &Node = node.as_ref().unwrap_or_else(|| {
+            panic!("Invalid formula");
+        });
+        let mut children: Vec<NaryNode> = Vec::new();
+
+        // the not node has only the right child
+        if node.left.borrow().is_some() {
+            children.push(NaryNode::from_node(Rc::clone(&node.left)));
+        }
+        if node.right.borrow().is_some() {
+            children.push(NaryNode::from_node(Rc::clone(&node.right)));
+        }
+
+        Self {
+            data: node.data.clone(),
+            children,
+        }
+    }
+
+    /// Get the children of the node
+    pub fn get_children(&self) -> &Vec<NaryNode> {
+        &self.children
+    }
+
+    /// Merge the nested AND (or OR) nodes into their AND (or OR) parent,
+    /// e.g. (A & (B & C)) & D becomes &(A, B, C, D)
+    pub fn flatten(&mut self) {
+        self.merge_nested(&[Symbols::And, Symbols::Or]);
+    }
+
+    /// Merge the nested AND nodes into their AND parent, the OR nodes keep their shape
+    pub fn flatten_conjunctions(&mut self) {
+        self.merge_nested(&[Symbols::And]);
+    }
+
+    fn merge_nested(&mut self, operators: &[Symbols]) {
+        let children: Vec<NaryNode> = std::mem::take(&mut self.children);
+
+        for mut child in children {
+            child.merge_nested(operators);
+            if operators.contains(&self.data) && child.data == self.data {
+                self.children.append(&mut child.children);
+            } else {
+                self.children.push(child);
+            }
+        }
+    }
+
+    /// Sort the operands of the commutative operators in the canonical order,
+    /// the literals come first ordered by their var (A < A! < B), then the other subtrees
+    pub fn sort(&mut self) {
+        for child in self.children.iter_mut() {
+            child.sort();
+        }
+        if is_commutative(&self.data) {
+            self.children.sort();
+        }
+    }
+
+    /// Flatten the tree and sort the operands, two formulas that only differ by the grouping
+    /// or the order of their operands get the same canonical tree
+    pub fn canonicalize(&mut self) {
+        self.flatten();
+        self.sort();
+    }
+
+    /// Get the RPN formula of the tree, the operators of an AND node are moved to its end
+    /// (ABC&&) while the operands of an OR node are chained from the left (AB|C|)
+    pub fn get_rpn_formula(&self) -> String {
+        let mut res: String = String::new();
+
+        match self.data {
+            Symbols::Char(c) => res.push(c),
+            Symbols::And => {
+                for child in self.children.iter() {
+                    res += &child.get_rpn_formula();
+                }
+                res += &"&".repeat(self.children.len() - 1);
+            }
+            _ => {
+                for (i, child) in self.children.iter().enumerate() {
+                    res += &child.get_rpn_formula();
+                    if i > 0 && i + 1 < self.children.len() {
+                        res += &get_symbol(&self.data);
+                    }
+                }
+                res += &get_symbol(&self.data);
+            }
+        }
+
+        res
+    }
+
+    fn get_literal(&self) -> Option<(char, bool)> {
+        match self.data {
+            Symbols::Char(c) => Some((c, false)),
+            Symbols::Not => match self.children[0].data {
+                Symbols::Char(c) => Some((c, true)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl Ord for NaryNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.get_literal(), other.get_literal()) {
+            (Some(lhs), Some(rhs)) => lhs.cmp(&rhs),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => (&self.data, &self.children).cmp(&(&other.data, &other.children)),
+        }
+    }
+}
+
+impl PartialOrd for NaryNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn is_commutative(data: &Symbols) -> bool {
+    !matches!(data, Symbols::Char(_) | Symbols::Not | Symbols::MatCond)
+}
+
+fn get_symbol(data: &Symbols) -> String {
+    match data {
+        Symbols::And => "&".to_string(),
+        Symbols::Or => "|".to_string(),
+        Symbols::Not => "!".to_string(),
+        Symbols::Xor => "^".to_string(),
+        Symbols::MatCond => ">".to_string(),
+        Symbols::LogEq => "=".to_string(),
+        Symbols::Nand => "↑".to_string(),
+        Symbols::Nor => "↓".to_string(),
+        Symbols::Xnor => "⊙".to_string(),
+        Symbols::Char(c) => c.to_string(),
+    }
+}
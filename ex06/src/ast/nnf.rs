@@ -72,6 +72,55 @@ fn get_equivalence_right_subtree(root: &mut Box<Node>) -> RcNode {
     mat_node
 }
 
+/// Rewrite the NAND, NOR and XNOR operators using the basic operators
+/// # Arguments
+/// * `curr_node` - The root of the AST
+pub fn rewrite_functional_operators(curr_node: RcNode) {
+    /*
+       In this function, we will rewrite the NAND, NOR and XNOR operators following these rules:
+       A ↑ B <=> !(A & B)
+       A ↓ B <=> !(A | B)
+       A ⊙ B <=> (A <=> B)
+       The algorithm is as follows:
+       1. Iterate through the AST
+       2. If the current node is a NAND (or NOR) operator, change it to a NOT operator
+       and move its children under a new AND (or OR) node
+       3. If the current node is a XNOR operator, change it to an equivalence operator
+           +---------------------+       +--------------------+
+           |    nand subtree     |       |    rewrite rule    |
+           +---------------------+       +--------------------+
+                    ↑                             !
+                   / \                             \
+                  /   \            --->              &
+                 /     \                           /   \
+                A       B                         A     B
+    */
+    if let Some(ref mut node) = curr_node.borrow_mut().as_mut() {
+        match node.data {
+            Symbols::Nand | Symbols::Nor => {
+                let symbol: Symbols = if let Symbols::Nand = node.data {
+                    Symbols::And
+                } else {
+                    Symbols::Or
+                };
+                let new_node: RcNode = Rc::new(RefCell::new(Some(Box::new(Node::new(symbol)))));
+
+                new_node.borrow_mut().as_mut().unwrap().left = Rc::clone(&node.left);
+                new_node.borrow_mut().as_mut().unwrap().right = Rc::clone(&node.right);
+                node.data = Symbols::Not;
+                node.left = Rc::new(RefCell::new(None));
+                node.right = new_node;
+            }
+            Symbols::Xnor => {
+                node.data = Symbols::LogEq;
+            }
+            _ => {}
+        }
+        rewrite_functional_operators(Rc::clone(&node.left));
+        rewrite_functional_operators(Rc::clone(&node.right));
+    }
+}
+
 /// Rewrite the equivalence operator
 /// # Arguments
 /// * `curr_node` - The root of the AST
@@ -2,26 +2,9 @@ mod truth_table;
 mod ast;
 
 use ast::AST;
+pub use ast::nary::NaryNode;
 use truth_table::TruthTable;
 
-fn move_conjunctions_to_end(formula: &str) -> String {
-    let mut res: String = String::new();
-    let mut conjunction_cnt: usize = 0;
-
-    for c in formula.as_bytes() {
-        if *c != b'&' {
-            res += &(*c as char).to_string();
-        }
-        conjunction_cnt += (*c == b'&') as usize;
-    }
-
-    for _i in 0..conjunction_cnt {
-        res += &"&";
-    }
-
-    res
-}
-
 /// Get conjunctive normal form
 /// # Arguments
 /// * `formula` -- The formula to get the cnf for
@@ -33,17 +16,86 @@ pub fn conjunctive_normal_form(formula: &str) -> String {
     
     tree.build(formula, true);
     tree.simplify_material_properties();
-    let nnf: String = tree.get_rpn_formula();
-    if tree.is_valid_cnf() {
-        return move_conjunctions_to_end(&nnf);
+    if !tree.is_valid_cnf() {
+        let mut truth_table: TruthTable = TruthTable::new(&tree.get_rpn_formula());
+
+        truth_table.fill();
+        truth_table.eval();
+        let cnf: String = truth_table.get_cnf_formula();
+
+        // a tautology has no clause
+        if cnf.is_empty() {
+            return cnf;
+        }
+        tree = AST::new();
+        tree.build(&cnf, true);
+    }
+    /*
+        Merge the nested conjunctions into a single AND node,
+        this way all the conjunctions are moved to the end of the formula.
+        The disjunctions are not merged so they keep the shape of the formula.
+     */
+    tree.get_conjunction_tree().get_rpn_formula()
+}
+
+/// Get the canonical tree of a formula, the nested AND and OR nodes are merged
+/// and the operands of the commutative operators are sorted
+/// # Arguments
+/// * `formula` -- The formula to get the canonical tree for
+/// # Example
+/// ```
+/// use ex06::canonical_tree;
+///
+/// assert_eq!(canonical_tree("AB&C&"), canonical_tree("CBA&&"));
+/// assert_ne!(canonical_tree("AB&C&"), canonical_tree("AB&C|"));
+/// ```
+pub fn canonical_tree(formula: &str) -> NaryNode {
+    if formula.is_empty() {
+        panic!("Invalid formula");
     }
-    let mut truth_table: TruthTable = TruthTable::new(&nnf);
+    let mut tree: AST = AST::new();
+
+    tree.build(formula, true);
+    let mut res: NaryNode = tree.get_nary_tree();
 
-    truth_table.fill();
-    truth_table.eval();
-    move_conjunctions_to_end(&truth_table.get_cnf_formula())
+    res.canonicalize();
+    res
+}
+
+/// Get the canonical form of a formula
+/// # Arguments
+/// * `formula` -- The formula to get the canonical form for
+/// # Example
+/// ```
+/// use ex06::canonical_form;
+///
+/// assert_eq!("ABC&&", canonical_form("CA&B&"));
+/// assert_eq!("AA!B|&", canonical_form("BA!|A&"));
+/// ```
+pub fn canonical_form(formula: &str) -> String {
+    canonical_tree(formula).get_rpn_formula()
+}
+
+/// Synthesize a formula into a structural Verilog module,
+/// see netlist::verilog_netlist
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// * `module` - The name of the module
+/// # Panics
+/// If the formula is invalid
+pub fn verilog_netlist(formula: &str, module: &str) -> String {
+    netlist::verilog_netlist(formula, module)
 }
 
+/// Synthesize a formula into a BLIF model, see netlist::blif_netlist
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// * `model` - The name of the model
+/// # Panics
+/// If the formula is invalid
+pub fn blif_netlist(formula: &str, model: &str) -> String {
+    netlist::blif_netlist(formula, model)
+}
 
 #[cfg(test)]
 mod tests {
@@ -58,6 +110,9 @@ mod tests {
         assert_eq!("ABCD&&&",  conjunctive_normal_form("AB&C&D&"));
         assert_eq!("A!B!|C!|",  conjunctive_normal_form("AB&!C!|"));
         assert_eq!("A!B!C!&&",  conjunctive_normal_form("AB|!C!&"));
+        // the disjunctions keep their shape, only the conjunctions are merged
+        assert_eq!("ABC||",  conjunctive_normal_form("ABC||"));
+        assert_eq!("AB|CD||EF&&",  conjunctive_normal_form("AB|CD||E&F&"));
     }
 
     #[test]
@@ -73,6 +128,58 @@ mod tests {
         assert_eq!("AB|D|AB!|D|A!B!|D|&&", conjunctive_normal_form("AB>D>"));
     }
     
+    #[test]
+    fn canonical_form_tests() {
+        assert_eq!("ABCD&&&", canonical_form("AB&C&D&"));
+        assert_eq!("ABCD&&&", canonical_form("DCBA&&&"));
+        assert_eq!("ABCD&&&", canonical_form("AB&CD&&"));
+        assert_eq!("AB|C|D|", canonical_form("AB|CD||"));
+        assert_eq!("AA!|B!|C|", canonical_form("CB!|A!A||"));
+        assert_eq!("AA!|B|C!|", canonical_form("C!B|A!A||"));
+        assert_eq!("CAB|&", canonical_form("AB|C&"));
+        assert_eq!("AB|CD&|", canonical_form("DC&BA||"));
+        assert_eq!("AB&CD&|", canonical_form("DC&BA&|"));
+        assert_eq!("CBA>^", canonical_form("BA>C^"));
+        assert_eq!("ABC&&!", canonical_form("CB&A&!"));
+        assert_eq!("AB↑", canonical_form("BA↑"));
+    }
+
+    #[test]
+    fn canonical_tree_tests() {
+        use std::collections::HashSet;
+
+        let mut trees: HashSet<NaryNode> = HashSet::new();
+
+        trees.insert(canonical_tree("AB&C&"));
+        trees.insert(canonical_tree("CA&B&"));
+        trees.insert(canonical_tree("BCA&&"));
+        assert_eq!(1, trees.len());
+        trees.insert(canonical_tree("AB|C&"));
+        trees.insert(canonical_tree("CBA|&"));
+        assert_eq!(2, trees.len());
+        assert_eq!(canonical_tree("AB>"), canonical_tree("AB>"));
+        assert_ne!(canonical_tree("AB>"), canonical_tree("BA>"));
+        assert_eq!(3, canonical_tree("AB&C&").get_children().len());
+        assert_eq!(2, canonical_tree("AB|C&").get_children().len());
+        // literals first, ordered by their var and then by their sign
+        let tree: NaryNode = canonical_tree("ZB&AB&A!&|C|");
+        let children: &Vec<NaryNode> = tree.get_children();
+        assert_eq!(3, children.len());
+        assert!(children[0] < children[1] && children[1] < children[2]);
+        assert_eq!("C", children[0].get_rpn_formula());
+        assert_eq!("AA!B&&", children[1].get_rpn_formula());
+        assert_eq!("BZ&", children[2].get_rpn_formula());
+    }
+
+    #[test]
+    fn functional_operators_tests() {
+        assert_eq!("A!B!|", conjunctive_normal_form("AB↑"));
+        assert_eq!("A!B!&", conjunctive_normal_form("AB↓"));
+        assert_eq!("AB&", conjunctive_normal_form("AB↑!"));
+        assert_eq!("AB|", conjunctive_normal_form("AB↓!"));
+        assert_eq!("A!B|B!A|&", conjunctive_normal_form("AB⊙"));
+    }
+
     #[test]
     #[should_panic(expected = "Invalid formula")]
     fn invalid_formula_empty_string_test() {
@@ -11,6 +11,27 @@ pub fn sat(formula: &str) -> bool {
     truth_table.is_sat()
 }
 
+/// Synthesize a formula into a structural Verilog module,
+/// see netlist::verilog_netlist
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// * `module` - The name of the module
+/// # Panics
+/// If the formula is invalid
+pub fn verilog_netlist(formula: &str, module: &str) -> String {
+    netlist::verilog_netlist(formula, module)
+}
+
+/// Synthesize a formula into a BLIF model, see netlist::blif_netlist
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// * `model` - The name of the model
+/// # Panics
+/// If the formula is invalid
+pub fn blif_netlist(formula: &str, model: &str) -> String {
+    netlist::blif_netlist(formula, model)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,6 +64,16 @@ mod tests {
         assert!(!sat("EE!="));
     }
 
+    #[test]
+    fn functional_operators_tests() {
+        assert!(sat("AB↑"));
+        assert!(sat("AB↓"));
+        assert!(sat("AB⊙"));
+        assert!(!sat("AA↑A&"));
+        assert!(!sat("AA↓A|!"));
+        assert!(!sat("AA!⊙"));
+    }
+
     #[test]
     #[should_panic(expected = "Invalid formula")]
     fn invalid_formula_empty_string_test() {
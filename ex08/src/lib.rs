@@ -1,19 +1,8 @@
+pub mod subsets;
+
 use std::collections::HashSet;
 
-fn fill_powerset(
-    set: &Vec<i32>,
-    ans: &mut Vec<Vec<i32>>,
-    tmp_set: &mut Vec<i32>,
-    start_index: usize,
-) {
-    ans.push(tmp_set.clone());
-
-    for i in start_index..set.len() {
-        tmp_set.push(set[i]);
-        fill_powerset(set, ans, tmp_set, i + 1);
-        tmp_set.pop();
-    }
-}
+pub use subsets::{combinations, nth_subset, powerset_bitmask, powerset_gray, powerset_iter, powerset_multiset};
 
 /// Get the powerset of a given set
 pub fn powerset(set: Vec<i32>) -> Vec<Vec<i32>> {
@@ -25,11 +14,20 @@ pub fn powerset(set: Vec<i32>) -> Vec<Vec<i32>> {
     if s.len() != set.len() {
         panic!("Error: Set with duplicate numbers");
     }
-    let mut ans: Vec<Vec<i32>> = Vec::new();
-    let mut tmp_set: Vec<i32> = Vec::new();
 
-    fill_powerset(&set, &mut ans, &mut tmp_set, 0);
-    ans
+    powerset_iter(&set).collect()
+}
+
+/// Get the powerset of a multiset, the set may hold the same number several times
+/// and each sub-bag is only given once
+/// # Examples
+/// ```
+/// use ex08::multiset_powerset;
+///
+/// assert_eq!(vec![vec![], vec![1], vec![1, 1]], multiset_powerset(vec![1, 1]));
+/// ```
+pub fn multiset_powerset(set: Vec<i32>) -> Vec<Vec<i32>> {
+    powerset_multiset(&set).collect()
 }
 
 
@@ -172,4 +170,115 @@ mod tests {
     fn set_with_more_then_one_duplicate_element_test() {
         powerset(vec![1, 1, 3, 3]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn generic_powerset_test() {
+        let set: Vec<String> = vec!["x".to_string(), "y".to_string(), "z".to_string()];
+        let subsets: Vec<Vec<String>> = powerset_iter(&set).collect();
+
+        assert_eq!(8, subsets.len());
+        assert_eq!(vec!["x".to_string(), "z".to_string()], subsets[4]);
+        // the iterator doesn't check the duplicates, nor build the subsets it doesn't reach
+        assert_eq!(Some(vec![1, 1]), powerset_iter(&[1, 1, 3]).nth(2));
+        let large: Vec<u64> = (0..100).collect();
+        assert_eq!(Some((0..100).collect::<Vec<u64>>()), powerset_iter(&large).nth(100));
+    }
+
+    #[test]
+    fn powerset_orders_test() {
+        for n in 0..=10 {
+            let set: Vec<i32> = (1..=n).collect();
+            let dfs: Vec<Vec<i32>> = powerset_iter(&set).collect();
+            let bitmask: Vec<Vec<i32>> = powerset_bitmask(&set).collect();
+            let gray: Vec<Vec<i32>> = powerset_gray(&set).collect();
+
+            assert_eq!(1 << n, dfs.len());
+            for (i, subset) in bitmask.iter().enumerate() {
+                let expected: Vec<i32> = set.iter().copied().filter(|x: &i32| i & (1 << (x - 1)) != 0).collect();
+
+                assert_eq!(&expected, subset);
+            }
+            for (i, subset) in gray.iter().enumerate() {
+                let code: usize = i ^ (i >> 1);
+                let expected: Vec<i32> = set.iter().copied().filter(|x: &i32| code & (1 << (x - 1)) != 0).collect();
+
+                assert_eq!(&expected, subset);
+            }
+            // the three orders hold the same subsets
+            let mut sorted_dfs: Vec<Vec<i32>> = dfs.clone();
+            let mut sorted_gray: Vec<Vec<i32>> = gray.clone();
+            let mut sorted_bitmask: Vec<Vec<i32>> = bitmask.clone();
+            sorted_dfs.sort();
+            sorted_gray.sort();
+            sorted_bitmask.sort();
+            assert_eq!(sorted_dfs, sorted_gray);
+            assert_eq!(sorted_dfs, sorted_bitmask);
+            for (i, subset) in dfs.iter().enumerate() {
+                assert_eq!(Some(subset.clone()), nth_subset(&set, i as u128));
+            }
+            assert_eq!(None, nth_subset(&set, 1 << n));
+        }
+        assert_eq!(
+            vec![vec![], vec![1], vec![1, 2], vec![2], vec![2, 3], vec![1, 2, 3], vec![1, 3], vec![3]],
+            powerset_gray(&[1, 2, 3]).collect::<Vec<Vec<i32>>>()
+        );
+    }
+
+    #[test]
+    fn nth_subset_large_set_test() {
+        let set: Vec<u32> = (0..200).collect();
+
+        // the last subset of the depth-first search is the last element alone
+        assert_eq!(Some(vec![119]), nth_subset(&set[..120], (1 << 120) - 1));
+        assert_eq!(Some(vec![0, 1, 2]), nth_subset(&set, 3));
+        assert_eq!(Some(vec![0, 2]), nth_subset(&set[..3], 4));
+        assert_eq!(Some(vec![1]), nth_subset(&set[..3], 5));
+    }
+
+    #[test]
+    fn combinations_test() {
+        let set: Vec<char> = vec!['a', 'b', 'c', 'd', 'e'];
+
+        for k in 0..=6 {
+            let subsets: Vec<Vec<char>> = combinations(&set, k).collect();
+            let expected: Vec<Vec<char>> = powerset_iter(&set).filter(|subset: &Vec<char>| subset.len() == k).collect();
+            let mut sorted: Vec<Vec<char>> = expected.clone();
+
+            sorted.sort();
+            // the combinations are in the lexicographic order of their indexes
+            assert_eq!(sorted, subsets);
+        }
+        assert_eq!(10, combinations(&set, 2).count());
+        assert_eq!(vec![Vec::<char>::new()], combinations(&set, 0).collect::<Vec<Vec<char>>>());
+        assert_eq!(184_756, combinations(&(0..20).collect::<Vec<i32>>(), 10).count());
+    }
+
+    #[test]
+    fn multiset_powerset_test() {
+        assert_eq!(
+            vec![
+                vec![],
+                vec![1],
+                vec![1, 1],
+                vec![1, 1, 3],
+                vec![1, 3],
+                vec![3],
+            ],
+            multiset_powerset(vec![1, 3, 1])
+        );
+        assert_eq!(vec![vec![] as Vec<i32>], multiset_powerset(vec![]));
+        assert_eq!(vec![vec![], vec![5], vec![5, 5], vec![5, 5, 5]], multiset_powerset(vec![5, 5, 5]));
+        // without duplicates it is the powerset of the sorted set
+        assert_eq!(powerset(vec![-1, 2, 3]), multiset_powerset(vec![3, -1, 2]));
+
+        // the number of sub-bags is the product of the number of copies of each value plus one
+        let set: Vec<i32> = vec![4, 1, 2, 2, 4, 4, 3, 1, 4];
+        let subsets: Vec<Vec<i32>> = multiset_powerset(set);
+        let mut sorted: Vec<Vec<i32>> = subsets.clone();
+
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(3 * 3 * 2 * 5, subsets.len());
+        assert_eq!(sorted.len(), subsets.len());
+    }
+}
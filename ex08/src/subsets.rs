@@ -0,0 +1,356 @@
+/// A lazy iterator over the subsets of a set in the lexicographic order of the
+/// depth-first search: [], [1], [1, 2], [1, 2, 3], [1, 3], [2], [2, 3], [3]
+#[derive(Debug, Clone)]
+pub struct Powerset<'a, T: Clone> {
+    set: &'a [T],
+    indexes: Vec<usize>,
+    started: bool,
+    done: bool,
+}
+
+impl<T: Clone> Iterator for Powerset<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            return Some(Vec::new());
+        }
+        /*
+            The next subset in the depth-first search is:
+            - the current one with the element after its last one if there is one
+            - otherwise the current one without its last element, where
+            the element before it is replaced by the next one
+         */
+        let next: usize = self.indexes.last().map_or(0, |last: &usize| last + 1);
+
+        if next < self.set.len() {
+            self.indexes.push(next);
+        } else {
+            self.indexes.pop();
+            match self.indexes.last_mut() {
+                Some(last) => *last += 1,
+                None => {
+                    self.done = true;
+                    return None;
+                }
+            }
+        }
+
+        Some(get_subset(self.set, &self.indexes))
+    }
+}
+
+/// A lazy iterator over the subsets of a set in the bitmask order, the element i
+/// of the set is in the subset when the bit i of the subset index is set:
+/// [], [1], [2], [1, 2], [3], [1, 3], [2, 3], [1, 2, 3]
+#[derive(Debug, Clone)]
+pub struct BitmaskPowerset<'a, T: Clone> {
+    set: &'a [T],
+    mask: Vec<bool>,
+    done: bool,
+}
+
+impl<T: Clone> Iterator for BitmaskPowerset<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        let res: Vec<T> = get_masked_subset(self.set, &self.mask);
+
+        // the last subset is reached when the counter wraps around to 0
+        self.done = increment(&mut self.mask).is_none();
+        Some(res)
+    }
+}
+
+/// A lazy iterator over the subsets of a set in the Gray code order, each subset
+/// only adds or removes one element from the previous one:
+/// [], [1], [1, 2], [2], [2, 3], [1, 2, 3], [1, 3], [3]
+#[derive(Debug, Clone)]
+pub struct GrayPowerset<'a, T: Clone> {
+    set: &'a [T],
+    counter: Vec<bool>,
+    mask: Vec<bool>,
+    done: bool,
+}
+
+impl<T: Clone> Iterator for GrayPowerset<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        let res: Vec<T> = get_masked_subset(self.set, &self.mask);
+
+        // the element to add or remove is the bit set by the increment of the counter
+        match increment(&mut self.counter) {
+            Some(i) => self.mask[i] = !self.mask[i],
+            None => self.done = true,
+        }
+        Some(res)
+    }
+}
+
+/// A lazy iterator over the subsets of k elements of a set in the lexicographic order:
+/// [1, 2], [1, 3], [2, 3]
+#[derive(Debug, Clone)]
+pub struct Combinations<'a, T: Clone> {
+    set: &'a [T],
+    indexes: Vec<usize>,
+    done: bool,
+}
+
+impl<T: Clone> Iterator for Combinations<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        let res: Vec<T> = get_subset(self.set, &self.indexes);
+        let (n, k) = (self.set.len(), self.indexes.len());
+
+        /*
+            Find the last index which can still move to the right, the index at
+            position i can go up to n - k + i, then move it and put the following
+            indexes right after it: with n = 5, [0, 3, 4] becomes [1, 2, 3]
+         */
+        match (0..k).rev().find(|i: &usize| self.indexes[*i] < n - k + i) {
+            Some(i) => {
+                self.indexes[i] += 1;
+                for j in i + 1..k {
+                    self.indexes[j] = self.indexes[j - 1] + 1;
+                }
+            }
+            None => self.done = true,
+        }
+        Some(res)
+    }
+}
+
+/// A lazy iterator over the sub-bags of a multiset in the depth-first search order of
+/// its sorted elements, each sub-bag is given once whatever the copies it is made of:
+/// [], [1], [1, 1], [1, 1, 2], [1, 2], [2]
+#[derive(Debug, Clone)]
+pub struct MultisetPowerset<T: Clone + Ord> {
+    set: Vec<T>,
+    indexes: Vec<usize>,
+    started: bool,
+    done: bool,
+}
+
+impl<T: Clone + Ord> Iterator for MultisetPowerset<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            return Some(Vec::new());
+        }
+        /*
+            Same as the depth-first search of the powerset, but when the last element
+            is replaced, it is replaced by the next different one, since taking another
+            copy of the same value at the same position would give the same sub-bag again
+         */
+        let next: usize = self.indexes.last().map_or(0, |last: &usize| last + 1);
+
+        if next < self.set.len() {
+            self.indexes.push(next);
+        } else {
+            loop {
+                let last: usize = match self.indexes.pop() {
+                    Some(last) => last,
+                    None => {
+                        self.done = true;
+                        return None;
+                    }
+                };
+
+                if let Some(j) = (last + 1..self.set.len()).find(|j: &usize| self.set[*j] != self.set[last]) {
+                    self.indexes.push(j);
+                    break;
+                }
+            }
+        }
+
+        Some(get_subset(&self.set, &self.indexes))
+    }
+}
+
+/// Get a lazy iterator over the subsets of the set in the depth-first search order,
+/// which is the order of the powerset function
+/// # Examples
+/// ```
+/// use ex08::powerset_iter;
+///
+/// let mut subsets = powerset_iter(&["a", "b"]);
+///
+/// assert_eq!(Some(vec![]), subsets.next());
+/// assert_eq!(Some(vec!["a"]), subsets.next());
+/// assert_eq!(Some(vec!["a", "b"]), subsets.next());
+/// assert_eq!(Some(vec!["b"]), subsets.next());
+/// assert_eq!(None, subsets.next());
+/// ```
+pub fn powerset_iter<T: Clone>(set: &[T]) -> Powerset<'_, T> {
+    Powerset {
+        set,
+        indexes: Vec::new(),
+        started: false,
+        done: false,
+    }
+}
+
+/// Get a lazy iterator over the subsets of the set in the bitmask order
+/// # Examples
+/// ```
+/// use ex08::powerset_bitmask;
+///
+/// let subsets: Vec<Vec<char>> = powerset_bitmask(&['a', 'b']).collect();
+///
+/// assert_eq!(vec![vec![], vec!['a'], vec!['b'], vec!['a', 'b']], subsets);
+/// ```
+pub fn powerset_bitmask<T: Clone>(set: &[T]) -> BitmaskPowerset<'_, T> {
+    BitmaskPowerset {
+        set,
+        mask: vec![false; set.len()],
+        done: false,
+    }
+}
+
+/// Get a lazy iterator over the subsets of the set in the Gray code order
+/// # Examples
+/// ```
+/// use ex08::powerset_gray;
+///
+/// let subsets: Vec<Vec<char>> = powerset_gray(&['a', 'b']).collect();
+///
+/// assert_eq!(vec![vec![], vec!['a'], vec!['a', 'b'], vec!['b']], subsets);
+/// ```
+pub fn powerset_gray<T: Clone>(set: &[T]) -> GrayPowerset<'_, T> {
+    GrayPowerset {
+        set,
+        counter: vec![false; set.len()],
+        mask: vec![false; set.len()],
+        done: false,
+    }
+}
+
+/// Get a lazy iterator over the distinct sub-bags of the multiset, the elements are
+/// sorted first so the copies of a value are next to each other
+/// # Examples
+/// ```
+/// use ex08::powerset_multiset;
+///
+/// let subsets: Vec<Vec<i32>> = powerset_multiset(&[2, 1, 2]).collect();
+///
+/// assert_eq!(vec![vec![], vec![1], vec![1, 2], vec![1, 2, 2], vec![2], vec![2, 2]], subsets);
+/// ```
+pub fn powerset_multiset<T: Clone + Ord>(set: &[T]) -> MultisetPowerset<T> {
+    let mut sorted: Vec<T> = set.to_vec();
+
+    sorted.sort();
+    MultisetPowerset {
+        set: sorted,
+        indexes: Vec::new(),
+        started: false,
+        done: false,
+    }
+}
+
+/// Get a lazy iterator over the subsets of k elements of the set
+/// # Examples
+/// ```
+/// use ex08::combinations;
+///
+/// let subsets: Vec<Vec<i32>> = combinations(&[1, 2, 3, 4], 3).collect();
+///
+/// assert_eq!(vec![vec![1, 2, 3], vec![1, 2, 4], vec![1, 3, 4], vec![2, 3, 4]], subsets);
+/// assert_eq!(0, combinations(&[1, 2], 3).count());
+/// ```
+pub fn combinations<T: Clone>(set: &[T], k: usize) -> Combinations<'_, T> {
+    Combinations {
+        set,
+        indexes: (0..k).collect(),
+        done: k > set.len(),
+    }
+}
+
+/// Get the subset at the index i of the depth-first search order without
+/// building the previous ones, or None if the set has less than i + 1 subsets
+/// # Examples
+/// ```
+/// use ex08::nth_subset;
+///
+/// assert_eq!(Some(vec![]), nth_subset(&[1, 2, 3], 0));
+/// assert_eq!(Some(vec![1, 3]), nth_subset(&[1, 2, 3], 4));
+/// assert_eq!(Some(vec![3]), nth_subset(&[1, 2, 3], 7));
+/// assert_eq!(None, nth_subset(&[1, 2, 3], 8));
+/// ```
+pub fn nth_subset<T: Clone>(set: &[T], mut i: u128) -> Option<Vec<T>> {
+    /*
+        In the depth-first search, the current subset comes first, then for
+        each following element j, the subsets starting with the current one and j,
+        there are 2 ^ (n - j - 1) of them. So we skip the elements whose subsets
+        are before i, and go down into the first one which holds it.
+     */
+    let mut res: Vec<T> = Vec::new();
+    let mut start: usize = 0;
+
+    while i > 0 {
+        i -= 1;
+        let next: usize = (start..set.len()).find(|j: &usize| {
+            let size: u128 = get_subsets_count(set.len() - j - 1);
+
+            if i < size {
+                return true;
+            }
+            i -= size;
+            false
+        })?;
+
+        res.push(set[next].clone());
+        start = next + 1;
+    }
+
+    Some(res)
+}
+
+/// Get 2 ^ n, or the maximum value if it doesn't fit
+fn get_subsets_count(n: usize) -> u128 {
+    1u128.checked_shl(n as u32).filter(|_| n < 128).unwrap_or(u128::MAX)
+}
+
+/// Add one to the binary counter, the lowest bit first, and return the index of the bit
+/// which has been set, or None if the counter wrapped around to 0
+fn increment(counter: &mut [bool]) -> Option<usize> {
+    for (i, bit) in counter.iter_mut().enumerate() {
+        *bit = !*bit;
+        if *bit {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+fn get_subset<T: Clone>(set: &[T], indexes: &[usize]) -> Vec<T> {
+    indexes.iter().map(|i: &usize| set[*i].clone()).collect()
+}
+
+fn get_masked_subset<T: Clone>(set: &[T], mask: &[bool]) -> Vec<T> {
+    set.iter()
+        .zip(mask.iter())
+        .filter(|(_, is_in)| **is_in)
+        .map(|(value, _)| value.clone())
+        .collect()
+}
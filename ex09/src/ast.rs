@@ -1,8 +1,8 @@
-pub mod nnf;
-
+use crate::engine::SetOps;
 use std::{
+    borrow::Cow,
     cell::RefCell,
-    collections::{BTreeSet, HashMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashMap},
     fmt::Debug,
     rc::Rc,
 };
@@ -17,6 +17,26 @@ enum Symbols {
     Xor,
     MatCond,
     LogEq,
+    Nand,
+    Nor,
+    Xnor,
+    Sum,
+    Diff,
+}
+
+/// A step of the formula in reverse polish notation, the vars are replaced
+/// by their index in alphabetical order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Var(usize),
+    Not,
+    And,
+    Or,
+    Xor,
+    MatCond,
+    LogEq,
+    Nand,
+    Nor,
 }
 
 type RcNode = Rc<RefCell<Option<Box<Node>>>>;
@@ -70,22 +90,23 @@ impl AST {
         let mut stack: Vec<char> = Vec::new();
         let mut is_oper: bool = false;
         let mut processed: usize = 0;
+        let formula_len: usize = formula.chars().count();
 
         self.formula = formula.to_string();
-        for c in formula.as_bytes() {
+        for c in formula.chars() {
             match c {
-                b'1' | b'0' if !allow_var => stack.push(*c as char),
-                b'A'..=b'Z' if allow_var => stack.push(*c as char),
-                b'|' => self.add_sub_tree(&mut stack, Symbols::Or),
-                b'&' => self.add_sub_tree(&mut stack, Symbols::And),
-                b'!' => {
+                '1' | '0' if !allow_var => stack.push(c),
+                'A'..='Z' if allow_var => stack.push(c),
+                '|' => self.add_sub_tree(&mut stack, Symbols::Or),
+                '&' => self.add_sub_tree(&mut stack, Symbols::And),
+                '!' => {
                     /*
                        if the previous character was an operator or if we are at the end of the
                        formula, we should add a new not node to the tree, otherwise we should
                        add the negation operator to the stack to use it again in the next iteration.
                     */
-                    if is_oper || (formula.len() - processed == 1) {
-                        self.add_not_node(&mut stack, formula.len() - processed == 1);
+                    if is_oper || (formula_len - processed == 1) {
+                        self.add_not_node(&mut stack, formula_len - processed == 1);
                     } else {
                         let top: char = stack.pop().unwrap_or_else(|| {
                             panic!("Invalid formula");
@@ -97,16 +118,21 @@ impl AST {
                         */
                         if top != '!' {
                             stack.push(top);
-                            stack.push(*c as char);
+                            stack.push(c);
                             self.not_cnt += 1;
                         } else {
                             self.not_cnt -= 1;
                         }
                     }
                 }
-                b'^' => self.add_sub_tree(&mut stack, Symbols::Xor),
-                b'>' => self.add_sub_tree(&mut stack, Symbols::MatCond),
-                b'=' => self.add_sub_tree(&mut stack, Symbols::LogEq),
+                '^' => self.add_sub_tree(&mut stack, Symbols::Xor),
+                '>' => self.add_sub_tree(&mut stack, Symbols::MatCond),
+                '=' => self.add_sub_tree(&mut stack, Symbols::LogEq),
+                '↑' => self.add_sub_tree(&mut stack, Symbols::Nand),
+                '↓' => self.add_sub_tree(&mut stack, Symbols::Nor),
+                '⊙' => self.add_sub_tree(&mut stack, Symbols::Xnor),
+                '+' => self.add_sub_tree(&mut stack, Symbols::Sum),
+                '-' => self.add_sub_tree(&mut stack, Symbols::Diff),
                 _ => panic!("Invalid formula"),
             }
             /*
@@ -122,13 +148,13 @@ impl AST {
                check if should add it to stack if the top of the stack is not a negation operator.
             */
             if allow_var {
-                is_oper = if (*c < b'A' || *c > b'Z') && *c != b'!' {
+                is_oper = if !c.is_ascii_uppercase() && c != '!' {
                     true
                 } else {
                     false
                 };
             } else {
-                is_oper = if *c != b'1' && *c != b'0' && *c != b'!' {
+                is_oper = if c != '1' && c != '0' && c != '!' {
                     true
                 } else {
                     false
@@ -156,69 +182,6 @@ impl AST {
         self.root = Rc::clone(&top);
     }
 
-    /// Simplify the material properties by applying the following rules:
-    /// 1. Rewrite the equivalence operator
-    /// 2. Rewrite the material conditions
-    /// 3. Rewrite the xor operator
-    /// 4. Eliminate double negation
-    /// 5. Apply the Morgan's law
-    pub fn simplify_material_properties(&mut self) {
-        /*
-           Apply the Rewrite equivalence rule on the tree:
-           (A <=> B) <=> (A => B) & (B => A)
-        */
-        nnf::rewrite_equivalence(Rc::clone(&self.root));
-        /*
-           Apply the Rewrite material conditions rule on the tree:
-           (A => B) <=> !A | B
-        */
-        nnf::rewrite_material_conditions(Rc::clone(&self.root));
-        /*
-           Apply the Rewrite xor operator rule on the tree:
-           A ^ B <=> (A & !B) | (B & !A)
-        */
-        nnf::rewrite_xor_operator(Rc::clone(&self.root));
-        /*
-           Eliminate double negation from the tree:
-           !!A <=> A
-        */
-        self.root = nnf::eliminate_double_negation(Rc::clone(&self.root), 0);
-        nnf::remove_double_negations(Rc::clone(&self.root));
-        /*
-           Apply the Morgan's law on the tree:
-           !(A & B) <=> !A | !B
-           !(A | B) <=> !A & !B
-           we start by applying the Morgan's law on the root of the tree if it's a not node,
-           and we decide if we should call the function on the subtrees or not,
-           if after applying the Morgan's law and we get the a character in the right subtree,
-           there is no need to call the function on the subtree.
-        */
-        let mut is_not_node: bool = false;
-
-        if let Symbols::Char(_) = self.root.borrow().as_ref().unwrap().data {
-            return;
-        }
-        if let Symbols::Not = self.root.borrow().as_ref().unwrap().data {
-            is_not_node = true;
-        }
-        if is_not_node {
-            let (right_subtree, call_subtree) = nnf::remove_not_node(Rc::clone(&self.root), true);
-
-            self.root = right_subtree;
-            if call_subtree {
-                nnf::morgan_law(Rc::clone(&self.root), is_not_node);
-            }
-        } else {
-            nnf::morgan_law(Rc::clone(&self.root), false);
-        }
-        nnf::remove_double_negations(Rc::clone(&self.root));
-    }
-
-    /// Get the RPN formula using Post Order Traversal
-    pub fn _get_rpn_formula(&self) -> String {
-        nnf::_get_rpn_formula(Rc::clone(&self.root))
-    }
-
     fn get_top(&mut self, stack: &mut Vec<char>) -> RcNode {
         let mut top: char = stack.pop().unwrap_or_else(|| {
             panic!("Invalid formula");
@@ -352,94 +315,205 @@ impl AST {
         }
     }
 
-    /// Evaluate the AST calling the recursive function eval_tree
-    pub fn eval(&self, sets: Vec<Vec<i32>>) -> Vec<i32> {
-        let mut unique_chars: BTreeSet<char> = BTreeSet::new();
-
-
-        /* Store unique characters in a BTreeSet */
-        for c in self.formula.chars() {
-            if c >= 'A' && c <= 'Z' {
-                unique_chars.insert(c);
+    /// Evaluate the AST calling the recursive function eval_tree, the result is sorted
+    /// as the indexes of the engines are in ascending order
+    /// # Arguments
+    /// * `sets` - The sets of the vars in alphabetical order
+    /// * `universe` - The universal set of the negation, the union of all sets if there is none
+    /// # Panics
+    /// If a set has duplicate elements, or elements which are not in the universal set
+    pub fn eval<T: Ord + Clone, S: SetOps>(&self, sets: Vec<Vec<T>>, universe: Option<Vec<T>>) -> Vec<T> {
+        let mut var_sets: Vec<S> = Vec::new();
+        /*
+            The universal set is sorted once, then each element is replaced by
+            its index in it, so the engine only works on indexes
+         */
+        let mut universal_set: Vec<T> = match universe {
+            Some(ref universe) => universe.clone(),
+            None => sets.iter().flatten().cloned().collect(),
+        };
+
+        universal_set.sort();
+        universal_set.dedup();
+        for (set_idx, set) in sets.iter().enumerate() {
+            let mut indexes: Vec<usize> = set
+                .iter()
+                .map(|n: &T| {
+                    universal_set.binary_search(n).unwrap_or_else(|_| {
+                        panic!("Set with index {set_idx} has elements outside of the universal set");
+                    })
+                })
+                .collect();
+
+            indexes.sort();
+            indexes.dedup();
+            if indexes.len() != set.len() {
+                panic!("Set with index {set_idx} has duplicate elements");
             }
+            var_sets.push(S::from_indexes(&indexes, universal_set.len()));
         }
-        if sets.len() != unique_chars.len() {
-            panic!("The number of sets should be equal to the number of vars in the formula");
-        }
-        let mut set_idx: usize = 0;
-        let mut var_set: HashMap<char, HashSet<i32>> = HashMap::new();
-        let mut universal_set: HashSet<i32> = HashSet::new();
 
+        self.eval_sets(var_sets)
+            .to_indexes()
+            .into_iter()
+            .map(|i: usize| universal_set[i].clone())
+            .collect()
+    }
+
+    /// Evaluate the AST on sets of any representation
+    /// # Arguments
+    /// * `sets` - The sets of the vars in alphabetical order
+    /// # Panics
+    /// If the number of vars doesn't match the number of sets
+    pub fn eval_sets<S: SetOps>(&self, sets: Vec<S>) -> S {
+        let unique_chars: BTreeSet<char> = self.get_vars(sets.len());
         /*
-            Iterate over the HashSet with unique characters and map
-            each character with a HashSet in order e.i, 'A' with the first set
+            Map each character with a set in order e.i, 'A' with the first set
             'B' with the second one and so on...
-            And while iterating we construct a universal set which is the union
-            of all sets.
          */
-        for it in unique_chars.iter() {
-            let mut tmp_set: HashSet<i32> = HashSet::new();
+        let var_set: HashMap<char, S> = unique_chars.into_iter().zip(sets).collect();
+
+        self.eval_tree(self.root.borrow().as_deref(), &var_set).into_owned()
+    }
 
-            for n in sets[set_idx].iter() {
-                tmp_set.insert(*n);
+    /// Evaluate the subtree, the sets of the vars are borrowed and not cloned
+    fn eval_tree<'a, S: SetOps>(&self, root: Option<&Node>, var_set: &'a HashMap<char, S>) -> Cow<'a, S> {
+        let node: &Node = root.unwrap();
+
+        match node.data {
+            Symbols::Char(c) => Cow::Borrowed(var_set.get(&c).unwrap()),
+            Symbols::Not => Cow::Owned(self.eval_tree(node.right.borrow().as_deref(), var_set).complement()),
+            _ => {
+                let lhs: Cow<S> = self.eval_tree(node.left.borrow().as_deref(), var_set);
+                let rhs: Cow<S> = self.eval_tree(node.right.borrow().as_deref(), var_set);
+
+                Cow::Owned(match node.data {
+                    Symbols::And => lhs.intersection(&rhs),
+                    Symbols::Or => lhs.union(&rhs),
+                    Symbols::Xor => lhs.symmetric_difference(&rhs),
+                    // A > B <=> (U \ A) ∪ B
+                    Symbols::MatCond => lhs.complement().union(&rhs),
+                    // A = B <=> U \ (A △ B)
+                    Symbols::LogEq | Symbols::Xnor => lhs.symmetric_difference(&rhs).complement(),
+                    Symbols::Nand => lhs.intersection(&rhs).complement(),
+                    Symbols::Nor => lhs.union(&rhs).complement(),
+                    _ => panic!("Invalid formula"),
+                })
             }
-            if tmp_set.len() != sets[set_idx].len() {
-                panic!("Set with index {set_idx} has duplicate elements");
+        }
+    }
+
+    /// Evaluate the AST on multisets, the result holds each element as many times
+    /// as its multiplicity, in ascending order
+    /// # Panics
+    /// If the formula uses an operator which has no meaning on multisets
+    pub fn eval_multiset<T: Ord + Clone>(&self, sets: Vec<Vec<T>>) -> Vec<T> {
+        let unique_chars: BTreeSet<char> = self.get_vars(sets.len());
+        let mut var_bag: HashMap<char, BTreeMap<T, usize>> = HashMap::new();
+
+        // each var is mapped with the multiplicity of each element of its multiset
+        for (it, set) in unique_chars.iter().zip(sets.iter()) {
+            let mut bag: BTreeMap<T, usize> = BTreeMap::new();
+
+            for n in set.iter() {
+                *bag.entry(n.clone()).or_insert(0) += 1;
             }
-            universal_set = universal_set.union(&tmp_set).cloned().collect();
-            var_set.insert(*it, tmp_set);
-            set_idx += 1;
+            var_bag.insert(*it, bag);
         }
 
-        self.eval_tree(self.root.borrow().as_ref(), &var_set, &universal_set)
-            .iter()
-            .cloned()
+        self.eval_multiset_tree(self.root.borrow().as_deref(), &var_bag)
+            .into_iter()
+            .flat_map(|(n, count)| std::iter::repeat_n(n, count))
             .collect()
     }
 
-    fn eval_tree(
+    /// Get the formula as a list of steps in reverse polish notation, to evaluate it
+    /// on the membership of each element instead of on whole sets
+    /// # Panics
+    /// If the number of vars doesn't match the number of sets
+    pub fn get_program(&self, sets_len: usize) -> Vec<Step> {
+        let vars: Vec<char> = self.get_vars(sets_len).into_iter().collect();
+        let mut program: Vec<Step> = Vec::new();
+
+        self.add_steps(self.root.borrow().as_deref(), &vars, &mut program);
+        program
+    }
+
+    fn add_steps(&self, root: Option<&Node>, vars: &[char], program: &mut Vec<Step>) {
+        let node: &Node = root.unwrap();
+
+        if let Symbols::Char(c) = node.data {
+            program.push(Step::Var(vars.binary_search(&c).unwrap()));
+            return;
+        }
+        if !matches!(node.data, Symbols::Not) {
+            self.add_steps(node.left.borrow().as_deref(), vars, program);
+        }
+        self.add_steps(node.right.borrow().as_deref(), vars, program);
+        program.push(match node.data {
+            Symbols::Not => Step::Not,
+            Symbols::And => Step::And,
+            Symbols::Or => Step::Or,
+            Symbols::Xor => Step::Xor,
+            Symbols::MatCond => Step::MatCond,
+            Symbols::LogEq | Symbols::Xnor => Step::LogEq,
+            Symbols::Nand => Step::Nand,
+            Symbols::Nor => Step::Nor,
+            _ => panic!("Invalid formula"),
+        });
+    }
+
+    /// Get the vars of the formula in alphabetical order
+    /// # Panics
+    /// If the number of vars doesn't match the number of sets
+    fn get_vars(&self, sets_len: usize) -> BTreeSet<char> {
+        let unique_chars: BTreeSet<char> = self
+            .formula
+            .chars()
+            .filter(|c: &char| c.is_ascii_uppercase())
+            .collect();
+
+        if sets_len != unique_chars.len() {
+            panic!("The number of sets should be equal to the number of vars in the formula");
+        }
+
+        unique_chars
+    }
+
+    fn eval_multiset_tree<T: Ord + Clone>(
         &self,
-        root: Option<&Box<Node>>,
-        var_set: &HashMap<char, HashSet<i32>>,
-        universal_set: &HashSet<i32>,
-    ) -> HashSet<i32> {
-        match root.as_ref().unwrap().data {
-            Symbols::And => self
-                .eval_tree(
-                    root.as_ref().unwrap().left.borrow().as_ref(),
-                    var_set,
-                    universal_set,
-                )
-                .intersection(&self.eval_tree(
-                    root.as_ref().unwrap().right.borrow().as_ref(),
-                    var_set,
-                    universal_set,
-                ))
-                .cloned()
-                .collect(),
-            Symbols::Or => self
-                .eval_tree(
-                    root.as_ref().unwrap().left.borrow().as_ref(),
-                    var_set,
-                    universal_set,
-                )
-                .union(&self.eval_tree(
-                    root.as_ref().unwrap().right.borrow().as_ref(),
-                    var_set,
-                    universal_set,
-                ))
-                .cloned()
-                .collect(),
-            Symbols::Not => universal_set
-                .difference(&self.eval_tree(
-                    root.as_ref().unwrap().right.borrow().as_ref(),
-                    var_set,
-                    universal_set,
-                ))
-                .cloned()
-                .collect(),
-            Symbols::Char(c) => var_set.get(&c).unwrap().clone(),
-            _ => HashSet::new(),
+        root: Option<&Node>,
+        var_bag: &HashMap<char, BTreeMap<T, usize>>,
+    ) -> BTreeMap<T, usize> {
+        let node: &Node = root.unwrap();
+
+        if let Symbols::Char(c) = node.data {
+            return var_bag.get(&c).unwrap().clone();
         }
+        if let Symbols::Not = node.data {
+            // a multiset has no complement, an element may be taken any number of times
+            panic!("Invalid multiset operator");
+        }
+        let lhs: BTreeMap<T, usize> = self.eval_multiset_tree(node.left.borrow().as_deref(), var_bag);
+        let rhs: BTreeMap<T, usize> = self.eval_multiset_tree(node.right.borrow().as_deref(), var_bag);
+        let merge: fn(usize, usize) -> usize = match node.data {
+            Symbols::And => |a, b| a.min(b),
+            Symbols::Or => |a, b| a.max(b),
+            Symbols::Sum => |a, b| a + b,
+            Symbols::Diff => |a, b| a.saturating_sub(b),
+            Symbols::Xor => |a, b| a.abs_diff(b),
+            _ => panic!("Invalid multiset operator"),
+        };
+        let keys: BTreeSet<T> = lhs.keys().chain(rhs.keys()).cloned().collect();
+
+        // the elements whose multiplicity falls to 0 are not in the result
+        keys.into_iter()
+            .map(|n: T| {
+                let count: usize = merge(*lhs.get(&n).unwrap_or(&0), *rhs.get(&n).unwrap_or(&0));
+
+                (n, count)
+            })
+            .filter(|(_, count)| *count > 0)
+            .collect()
     }
 }
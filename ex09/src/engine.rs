@@ -0,0 +1,99 @@
+pub mod bitset;
+pub mod interval;
+pub mod roaring;
+
+pub use bitset::BitSet;
+pub use interval::IntervalSet;
+pub use roaring::RoaringBitmap;
+
+use std::collections::HashSet;
+
+/// The set algebra used to evaluate the formulas. The elements of the universal set
+/// are sorted and replaced by their index, so a set only holds indexes lower than
+/// the size of the universe
+pub trait SetOps: Clone {
+    /// Build the set from its indexes in ascending order
+    /// # Arguments
+    /// * `indexes` - The indexes of the elements of the set, in ascending order
+    /// * `universe_len` - The number of elements of the universal set
+    fn from_indexes(indexes: &[usize], universe_len: usize) -> Self;
+
+    /// Get the indexes of the elements of the set in ascending order
+    fn to_indexes(&self) -> Vec<usize>;
+
+    fn union(&self, other: &Self) -> Self;
+
+    fn intersection(&self, other: &Self) -> Self;
+
+    /// Get the elements which are in only one of the sets
+    fn symmetric_difference(&self, other: &Self) -> Self;
+
+    /// Get the elements of the universal set which are not in the set
+    fn complement(&self) -> Self;
+}
+
+/// The representation of the sets while evaluating a formula
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Engine {
+    /// A hash set of indexes, one hash per element and per operation
+    HashSet,
+    /// A dense bitset, one bit per element of the universal set
+    #[default]
+    BitSet,
+    /// A roaring bitmap, chunks of 2 ^ 16 elements stored as sorted arrays
+    /// when they are sparse and as bitsets otherwise
+    Roaring,
+    /// Sorted and disjoint ranges, the fastest on sets of contiguous elements
+    Intervals,
+}
+
+/// A set of indexes stored in a HashSet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexHashSet {
+    set: HashSet<usize>,
+    universe_len: usize,
+}
+
+impl SetOps for IndexHashSet {
+    fn from_indexes(indexes: &[usize], universe_len: usize) -> Self {
+        Self {
+            set: indexes.iter().copied().collect(),
+            universe_len,
+        }
+    }
+
+    fn to_indexes(&self) -> Vec<usize> {
+        let mut res: Vec<usize> = self.set.iter().copied().collect();
+
+        res.sort();
+        res
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        Self {
+            set: self.set.union(&other.set).copied().collect(),
+            universe_len: self.universe_len,
+        }
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        Self {
+            set: self.set.intersection(&other.set).copied().collect(),
+            universe_len: self.universe_len,
+        }
+    }
+
+    fn symmetric_difference(&self, other: &Self) -> Self {
+        Self {
+            set: self.set.symmetric_difference(&other.set).copied().collect(),
+            universe_len: self.universe_len,
+        }
+    }
+
+    fn complement(&self) -> Self {
+        Self {
+            set: (0..self.universe_len).filter(|i: &usize| !self.set.contains(i)).collect(),
+            universe_len: self.universe_len,
+        }
+    }
+}
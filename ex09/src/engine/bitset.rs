@@ -0,0 +1,78 @@
+use super::SetOps;
+
+/// A dense set of indexes, the bit i of the words is set when the index i is in the set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitSet {
+    words: Vec<u64>,
+    universe_len: usize,
+}
+
+impl BitSet {
+    fn zip_with(&self, other: &Self, op: fn(u64, u64) -> u64) -> Self {
+        Self {
+            words: self
+                .words
+                .iter()
+                .zip(other.words.iter())
+                .map(|(a, b)| op(*a, *b))
+                .collect(),
+            universe_len: self.universe_len,
+        }
+    }
+}
+
+impl SetOps for BitSet {
+    fn from_indexes(indexes: &[usize], universe_len: usize) -> Self {
+        let mut words: Vec<u64> = vec![0; universe_len.div_ceil(64)];
+
+        for i in indexes.iter() {
+            words[i / 64] |= 1 << (i % 64);
+        }
+
+        Self { words, universe_len }
+    }
+
+    fn to_indexes(&self) -> Vec<usize> {
+        let mut res: Vec<usize> = Vec::new();
+
+        for (i, word) in self.words.iter().enumerate() {
+            let mut word: u64 = *word;
+
+            // take the lowest set bit until the word is empty
+            while word != 0 {
+                res.push(i * 64 + word.trailing_zeros() as usize);
+                word &= word - 1;
+            }
+        }
+
+        res
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    fn symmetric_difference(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a ^ b)
+    }
+
+    fn complement(&self) -> Self {
+        let mut words: Vec<u64> = self.words.iter().map(|word: &u64| !word).collect();
+
+        // the bits after the end of the universal set stay cleared
+        if !self.universe_len.is_multiple_of(64) {
+            if let Some(last) = words.last_mut() {
+                *last &= (1 << (self.universe_len % 64)) - 1;
+            }
+        }
+
+        Self {
+            words,
+            universe_len: self.universe_len,
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use super::SetOps;
+use std::ops::{Range, RangeInclusive};
+
+/// A set of integers stored as sorted and disjoint ranges, so a contiguous range of
+/// IDs takes the same memory whatever its size. The complement is relative to the
+/// bounding range of the set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet {
+    // the ranges are half-open, sorted, disjoint and never adjacent
+    ranges: Vec<Range<i64>>,
+    bounds: Range<i64>,
+}
+
+impl IntervalSet {
+    /// Get an empty set
+    /// # Arguments
+    /// * `bounds` - The bounding range, which the complement is relative to
+    pub fn new(bounds: RangeInclusive<i32>) -> Self {
+        Self {
+            ranges: Vec::new(),
+            bounds: *bounds.start() as i64..*bounds.end() as i64 + 1,
+        }
+    }
+
+    /// Get the set holding the union of the ranges, they may overlap
+    /// # Arguments
+    /// * `ranges` - The ranges of the set in any order
+    /// * `bounds` - The bounding range, which the complement is relative to
+    /// # Examples
+    /// ```
+    /// use ex09::{IntervalSet, SetOps};
+    ///
+    /// let set: IntervalSet = IntervalSet::from_ranges(&[8..=9, 1..=3, 2..=5], 0..=10);
+    ///
+    /// assert_eq!(vec![1..=5, 8..=9], set.get_ranges());
+    /// assert_eq!(vec![0..=0, 6..=7, 10..=10], set.complement().get_ranges());
+    /// ```
+    /// # Panics
+    /// If a range is not in the bounding range
+    pub fn from_ranges(ranges: &[RangeInclusive<i32>], bounds: RangeInclusive<i32>) -> Self {
+        let mut res: IntervalSet = Self::new(bounds);
+        let mut sorted: Vec<Range<i64>> = ranges
+            .iter()
+            .filter(|range: &&RangeInclusive<i32>| !range.is_empty())
+            .map(|range: &RangeInclusive<i32>| *range.start() as i64..*range.end() as i64 + 1)
+            .collect();
+
+        sorted.sort_by_key(|range: &Range<i64>| range.start);
+        for range in sorted {
+            if range.start < res.bounds.start || range.end > res.bounds.end {
+                panic!("Range {}..={} is outside of the bounds", range.start, range.end - 1);
+            }
+            push_range(&mut res.ranges, range);
+        }
+
+        res
+    }
+
+    /// Get the set holding the values
+    /// # Panics
+    /// If a value is not in the bounding range
+    pub fn from_values(values: &[i32], bounds: RangeInclusive<i32>) -> Self {
+        let ranges: Vec<RangeInclusive<i32>> = values.iter().map(|value: &i32| *value..=*value).collect();
+
+        Self::from_ranges(&ranges, bounds)
+    }
+
+    /// Get the values of the set in ascending order
+    pub fn to_values(&self) -> Vec<i32> {
+        self.ranges
+            .iter()
+            .flat_map(|range: &Range<i64>| range.clone().map(|value: i64| value as i32))
+            .collect()
+    }
+
+    /// Get the ranges of the set in ascending order
+    pub fn get_ranges(&self) -> Vec<RangeInclusive<i32>> {
+        self.ranges
+            .iter()
+            .map(|range: &Range<i64>| range.start as i32..=(range.end - 1) as i32)
+            .collect()
+    }
+
+    /// Get the bounding range of the set
+    pub fn get_bounds(&self) -> RangeInclusive<i32> {
+        self.bounds.start as i32..=(self.bounds.end - 1) as i32
+    }
+
+    /// Get the number of values of the set
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(|range: &Range<i64>| (range.end - range.start) as u64).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: i32) -> bool {
+        let value: i64 = value as i64;
+        // the index of the first range ending after the value
+        let i: usize = self.ranges.partition_point(|range: &Range<i64>| range.end <= value);
+
+        i < self.ranges.len() && self.ranges[i].start <= value
+    }
+
+    /// Combine the ranges of both sets by sweeping the bounds of all the ranges
+    /// # Arguments
+    /// * `keep` - If a value is kept depending on if it is in each set
+    fn combine(&self, other: &Self, keep: fn(bool, bool) -> bool) -> Self {
+        let mut points: Vec<i64> = self
+            .ranges
+            .iter()
+            .chain(other.ranges.iter())
+            .flat_map(|range: &Range<i64>| [range.start, range.end])
+            .collect();
+        let mut ranges: Vec<Range<i64>> = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        points.sort();
+        points.dedup();
+        // between two consecutive points, each set either holds all the values or none
+        for segment in points.windows(2) {
+            while i < self.ranges.len() && self.ranges[i].end <= segment[0] {
+                i += 1;
+            }
+            while j < other.ranges.len() && other.ranges[j].end <= segment[0] {
+                j += 1;
+            }
+            let in_a: bool = i < self.ranges.len() && self.ranges[i].start <= segment[0];
+            let in_b: bool = j < other.ranges.len() && other.ranges[j].start <= segment[0];
+
+            if keep(in_a, in_b) {
+                push_range(&mut ranges, segment[0]..segment[1]);
+            }
+        }
+
+        Self {
+            ranges,
+            bounds: self.bounds.clone(),
+        }
+    }
+}
+
+impl SetOps for IntervalSet {
+    fn from_indexes(indexes: &[usize], universe_len: usize) -> Self {
+        let mut ranges: Vec<Range<i64>> = Vec::new();
+
+        for i in indexes.iter() {
+            push_range(&mut ranges, *i as i64..*i as i64 + 1);
+        }
+
+        Self {
+            ranges,
+            bounds: 0..universe_len as i64,
+        }
+    }
+
+    fn to_indexes(&self) -> Vec<usize> {
+        self.ranges
+            .iter()
+            .flat_map(|range: &Range<i64>| range.start as usize..range.end as usize)
+            .collect()
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a || b)
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a && b)
+    }
+
+    fn symmetric_difference(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a != b)
+    }
+
+    fn complement(&self) -> Self {
+        let mut ranges: Vec<Range<i64>> = Vec::new();
+        let mut start: i64 = self.bounds.start;
+
+        // the complement is made of the gaps between the ranges
+        for range in self.ranges.iter() {
+            if start < range.start {
+                ranges.push(start..range.start);
+            }
+            start = range.end;
+        }
+        if start < self.bounds.end {
+            ranges.push(start..self.bounds.end);
+        }
+
+        Self {
+            ranges,
+            bounds: self.bounds.clone(),
+        }
+    }
+}
+
+/// Add a range after the last one, they are merged if they overlap or are adjacent
+fn push_range(ranges: &mut Vec<Range<i64>>, range: Range<i64>) {
+    match ranges.last_mut() {
+        Some(last) if last.end >= range.start => last.end = last.end.max(range.end),
+        _ => ranges.push(range),
+    }
+}
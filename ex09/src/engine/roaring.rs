@@ -0,0 +1,244 @@
+use super::SetOps;
+
+/// The number of indexes held by a chunk
+const CHUNK_LEN: usize = 1 << 16;
+/// Above this number of indexes, an array takes more memory than a bitmap
+const ARRAY_MAX_LEN: usize = 4096;
+
+/// The indexes of a chunk, either in a sorted array or in a bitmap of 2 ^ 16 bits
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Container {
+    Array(Vec<u16>),
+    Bitmap(Vec<u64>),
+}
+
+impl Container {
+    /// Get the container holding the bits of the words, or None if there is none
+    fn from_words(words: Vec<u64>) -> Option<Self> {
+        let count: usize = words.iter().map(|word: &u64| word.count_ones() as usize).sum();
+
+        match count {
+            0 => None,
+            _ if count <= ARRAY_MAX_LEN => Some(Container::Array(get_word_indexes(&words))),
+            _ => Some(Container::Bitmap(words)),
+        }
+    }
+
+    fn from_array(values: Vec<u16>) -> Option<Self> {
+        match values.len() {
+            0 => None,
+            len if len <= ARRAY_MAX_LEN => Some(Container::Array(values)),
+            _ => Some(Container::Bitmap(get_words(&values))),
+        }
+    }
+
+    fn to_words(&self) -> Vec<u64> {
+        match self {
+            Container::Array(values) => get_words(values),
+            Container::Bitmap(words) => words.clone(),
+        }
+    }
+
+    fn to_array(&self) -> Vec<u16> {
+        match self {
+            Container::Array(values) => values.clone(),
+            Container::Bitmap(words) => get_word_indexes(words),
+        }
+    }
+}
+
+/// A compressed set of indexes, the indexes are split in chunks of 2 ^ 16 and the
+/// empty chunks are not stored. The sparse chunks are sorted arrays and the dense
+/// ones are bitmaps, so the operations on two sparse chunks don't touch 2 ^ 16 bits
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoaringBitmap {
+    chunks: Vec<(usize, Container)>,
+    universe_len: usize,
+}
+
+impl RoaringBitmap {
+    /// Combine the chunks of both sets
+    /// # Arguments
+    /// * `keep` - If an index is kept depending on if it is in each set
+    /// * `op` - The same operation on the words of the bitmaps
+    fn combine(&self, other: &Self, keep: fn(bool, bool) -> bool, op: fn(u64, u64) -> u64) -> Self {
+        let mut chunks: Vec<(usize, Container)> = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        // merge the chunks by their key, as in the merge of two sorted lists
+        while i < self.chunks.len() || j < other.chunks.len() {
+            let lhs: Option<&(usize, Container)> = self.chunks.get(i);
+            let rhs: Option<&(usize, Container)> = other.chunks.get(j);
+            let (key, container): (usize, Option<Container>) = match (lhs, rhs) {
+                (Some((a_key, a)), Some((b_key, b))) if a_key == b_key => {
+                    i += 1;
+                    j += 1;
+                    (*a_key, combine_containers(a, b, keep, op))
+                }
+                (Some((a_key, a)), Some((b_key, _))) if a_key < b_key => {
+                    i += 1;
+                    (*a_key, Some(a.clone()).filter(|_| keep(true, false)))
+                }
+                (Some((a_key, a)), None) => {
+                    i += 1;
+                    (*a_key, Some(a.clone()).filter(|_| keep(true, false)))
+                }
+                (_, Some((b_key, b))) => {
+                    j += 1;
+                    (*b_key, Some(b.clone()).filter(|_| keep(false, true)))
+                }
+                (None, None) => unreachable!(),
+            };
+
+            if let Some(container) = container {
+                chunks.push((key, container));
+            }
+        }
+
+        Self {
+            chunks,
+            universe_len: self.universe_len,
+        }
+    }
+}
+
+impl SetOps for RoaringBitmap {
+    fn from_indexes(indexes: &[usize], universe_len: usize) -> Self {
+        let mut chunks: Vec<(usize, Container)> = Vec::new();
+
+        for chunk in indexes.chunk_by(|a: &usize, b: &usize| a / CHUNK_LEN == b / CHUNK_LEN) {
+            let values: Vec<u16> = chunk.iter().map(|i: &usize| (i % CHUNK_LEN) as u16).collect();
+
+            if let Some(container) = Container::from_array(values) {
+                chunks.push((chunk[0] / CHUNK_LEN, container));
+            }
+        }
+
+        Self { chunks, universe_len }
+    }
+
+    fn to_indexes(&self) -> Vec<usize> {
+        self.chunks
+            .iter()
+            .flat_map(|(key, container)| {
+                container
+                    .to_array()
+                    .into_iter()
+                    .map(move |value: u16| key * CHUNK_LEN + value as usize)
+            })
+            .collect()
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a || b, |a, b| a | b)
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a && b, |a, b| a & b)
+    }
+
+    fn symmetric_difference(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a != b, |a, b| a ^ b)
+    }
+
+    fn complement(&self) -> Self {
+        let mut chunks: Vec<(usize, Container)> = Vec::new();
+        let mut stored = self.chunks.iter().peekable();
+
+        // the missing chunks are empty, so they become full
+        for key in 0..self.universe_len.div_ceil(CHUNK_LEN) {
+            let mut words: Vec<u64> = match stored.next_if(|(stored_key, _)| *stored_key == key) {
+                Some((_, container)) => container.to_words(),
+                None => vec![0; CHUNK_LEN / 64],
+            };
+            let len: usize = (self.universe_len - key * CHUNK_LEN).min(CHUNK_LEN);
+
+            for (i, word) in words.iter_mut().enumerate() {
+                // only the bits before the end of the universal set are set
+                let valid: u64 = match len.saturating_sub(i * 64) {
+                    0 => 0,
+                    n if n >= 64 => u64::MAX,
+                    n => (1 << n) - 1,
+                };
+
+                *word = !*word & valid;
+            }
+            if let Some(container) = Container::from_words(words) {
+                chunks.push((key, container));
+            }
+        }
+
+        Self {
+            chunks,
+            universe_len: self.universe_len,
+        }
+    }
+}
+
+fn combine_containers(
+    a: &Container,
+    b: &Container,
+    keep: fn(bool, bool) -> bool,
+    op: fn(u64, u64) -> u64,
+) -> Option<Container> {
+    match (a, b) {
+        (Container::Array(a), Container::Array(b)) => {
+            let mut res: Vec<u16> = Vec::new();
+            let (mut i, mut j) = (0, 0);
+
+            while i < a.len() || j < b.len() {
+                let value: u16 = match (a.get(i), b.get(j)) {
+                    (Some(x), Some(y)) => *x.min(y),
+                    (Some(x), None) => *x,
+                    (None, Some(y)) => *y,
+                    (None, None) => unreachable!(),
+                };
+                let in_a: bool = a.get(i) == Some(&value);
+                let in_b: bool = b.get(j) == Some(&value);
+
+                if keep(in_a, in_b) {
+                    res.push(value);
+                }
+                i += in_a as usize;
+                j += in_b as usize;
+            }
+
+            Container::from_array(res)
+        }
+        _ => {
+            let words: Vec<u64> = a
+                .to_words()
+                .iter()
+                .zip(b.to_words().iter())
+                .map(|(x, y)| op(*x, *y))
+                .collect();
+
+            Container::from_words(words)
+        }
+    }
+}
+
+fn get_words(values: &[u16]) -> Vec<u64> {
+    let mut words: Vec<u64> = vec![0; CHUNK_LEN / 64];
+
+    for value in values.iter() {
+        words[*value as usize / 64] |= 1 << (value % 64);
+    }
+
+    words
+}
+
+fn get_word_indexes(words: &[u64]) -> Vec<u16> {
+    let mut res: Vec<u16> = Vec::new();
+
+    for (i, word) in words.iter().enumerate() {
+        let mut word: u64 = *word;
+
+        while word != 0 {
+            res.push((i * 64) as u16 + word.trailing_zeros() as u16);
+            word &= word - 1;
+        }
+    }
+
+    res
+}
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+/// Bind the vars of a formula to the sets of an environment, a var is either an
+/// uppercase letter or a name between braces like {users}. Since the AST only knows
+/// the letters, each name is renamed to a letter in the order of its first appearance.
+/// Returns the formula with the letters and the sets in alphabetical order of the letters
+/// # Arguments
+/// * `formula` - The formula with the named vars
+/// * `env` - The set of each var
+/// # Panics
+/// If a name is invalid, if the formula has more than 26 vars, if a var has no set,
+/// or if a set is not used by the formula
+pub fn bind_vars<T>(formula: &str, mut env: HashMap<String, Vec<T>>) -> (String, Vec<Vec<T>>) {
+    let mut names: Vec<String> = Vec::new();
+    let mut res: String = String::new();
+    let mut chars = formula.chars();
+
+    while let Some(c) = chars.next() {
+        let name: String = match c {
+            'A'..='Z' => c.to_string(),
+            '{' => {
+                let mut name: String = String::new();
+
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => panic!("Invalid formula"),
+                    }
+                }
+                if name.is_empty() || !name.chars().all(|c: char| c.is_alphanumeric() || c == '_') {
+                    panic!("Invalid var name {{{name}}}");
+                }
+                name
+            }
+            _ => {
+                res.push(c);
+                continue;
+            }
+        };
+        let idx: usize = match names.iter().position(|n: &String| *n == name) {
+            Some(idx) => idx,
+            None => {
+                names.push(name);
+                names.len() - 1
+            }
+        };
+
+        if idx >= 26 {
+            panic!("The formula has more than 26 vars");
+        }
+        res.push((b'A' + idx as u8) as char);
+    }
+
+    // the letters are given in the order of the names, which is their alphabetical order
+    let sets: Vec<Vec<T>> = names
+        .iter()
+        .map(|name: &String| {
+            env.remove(name).unwrap_or_else(|| {
+                panic!("Unbound var {name}");
+            })
+        })
+        .collect();
+
+    if let Some(name) = env.keys().min() {
+        panic!("Unused var {name}");
+    }
+
+    (res, sets)
+}
@@ -1,23 +1,218 @@
 mod ast;
+pub mod engine;
+mod env;
+mod stream;
 
 use ast::AST;
+pub use engine::{BitSet, Engine, IndexHashSet, IntervalSet, RoaringBitmap, SetOps};
+pub use stream::SortedSetEval;
+use std::collections::HashMap;
 
-/// Evaluate sets depending on the formula
+/// Evaluate sets depending on the formula, the negation is the complement in the
+/// union of all sets and the result is sorted
 /// # Example
 /// ```
 /// use ex09::eval_set;
 /// 
 /// eval_set("AB&", vec![vec![0, 1, 2], vec![0, 3, 4]]);
+/// assert_eq!(vec!["a", "b"], eval_set("AB&!", vec![vec!["a", "c"], vec!["b", "c"]]));
 /// ```
-pub fn eval_set(formula: &str, sets: Vec<Vec<i32>>) -> Vec<i32> {
+pub fn eval_set<T: Ord + Clone>(formula: &str, sets: Vec<Vec<T>>) -> Vec<T> {
+    eval_set_in(formula, sets, None, Engine::default())
+}
+
+/// Evaluate sets depending on the formula, the negation is the complement in the
+/// given universal set and the result is sorted
+/// # Arguments
+/// * `formula` - The formula
+/// * `sets` - The sets of the vars in alphabetical order
+/// * `universe` - The universal set, every set should be included in it
+/// # Example
+/// ```
+/// use ex09::eval_set_with_universe;
+///
+/// assert_eq!(vec![3, 4], eval_set_with_universe("A!", vec![vec![1, 2]], vec![1, 2, 3, 4]));
+/// ```
+/// # Panics
+/// If a set has elements which are not in the universal set
+pub fn eval_set_with_universe<T: Ord + Clone>(formula: &str, sets: Vec<Vec<T>>, universe: Vec<T>) -> Vec<T> {
+    eval_set_in(formula, sets, Some(universe), Engine::default())
+}
+
+/// Evaluate sets depending on the formula with the given representation of the sets,
+/// the bitsets are the fastest on dense sets and the roaring bitmaps on sparse ones,
+/// the result is sorted whatever the engine
+/// # Arguments
+/// * `formula` - The formula
+/// * `sets` - The sets of the vars in alphabetical order
+/// * `engine` - The representation of the sets while evaluating the formula
+/// # Example
+/// ```
+/// use ex09::{eval_set_with_engine, Engine};
+///
+/// let sets: Vec<Vec<u32>> = vec![(0..100_000).collect(), (50_000..200_000).collect()];
+///
+/// assert_eq!(50_000, eval_set_with_engine("AB&", sets.clone(), Engine::BitSet).len());
+/// assert_eq!(150_000, eval_set_with_engine("AB^", sets, Engine::Roaring).len());
+/// ```
+pub fn eval_set_with_engine<T: Ord + Clone>(formula: &str, sets: Vec<Vec<T>>, engine: Engine) -> Vec<T> {
+    eval_set_in(formula, sets, None, engine)
+}
+
+/// Evaluate sets depending on the formula, each var is bound to the set of the
+/// same name, a var is either an uppercase letter or a name between braces
+/// # Arguments
+/// * `formula` - The formula, like "{admins}{users}!&"
+/// * `env` - The set of each var of the formula
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use ex09::eval_set_env;
+///
+/// let env: HashMap<String, Vec<i32>> = HashMap::from([
+///     ("admins".to_string(), vec![1, 2]),
+///     ("C".to_string(), vec![2, 3]),
+/// ]);
+///
+/// assert_eq!(vec![1], eval_set_env("{admins}C!&", env));
+/// ```
+/// # Panics
+/// If a var has no set, or if a set is not used by the formula
+pub fn eval_set_env<T: Ord + Clone>(formula: &str, env: HashMap<String, Vec<T>>) -> Vec<T> {
+    let (formula, sets) = env::bind_vars(formula, env);
+
+    eval_set_in(&formula, sets, None, Engine::default())
+}
+
+/// Evaluate interval sets depending on the formula, the sets are never expanded so
+/// a range of billions of values costs as much as a single value. The negation is the
+/// complement in the bounding range of the sets
+/// # Example
+/// ```
+/// use ex09::{eval_set_intervals, IntervalSet};
+///
+/// let bounds = i32::MIN..=i32::MAX;
+/// let ids: IntervalSet = IntervalSet::from_ranges(&[1000..=5000, 1_000_000..=2_000_000_000], bounds.clone());
+/// let banned: IntervalSet = IntervalSet::from_values(&[1500, 3000], bounds);
+/// let res: IntervalSet = eval_set_intervals("AB!&", vec![ids, banned]);
+///
+/// assert_eq!(vec![1000..=1499, 1501..=2999, 3001..=5000, 1_000_000..=2_000_000_000], res.get_ranges());
+/// assert_eq!(1_999_004_000, res.len());
+/// ```
+/// # Panics
+/// If the formula is invalid, or if the sets don't have the same bounding range
+pub fn eval_set_intervals(formula: &str, sets: Vec<IntervalSet>) -> IntervalSet {
+    if formula.is_empty() || formula.contains(['+', '-']) {
+        panic!("Invalid formula");
+    }
+    if let Some(i) = sets.iter().position(|set: &IntervalSet| set.get_bounds() != sets[0].get_bounds()) {
+        panic!("Set with index {i} has different bounds");
+    }
+    let mut tree: AST = AST::new();
+
+    tree.build(formula, true);
+    tree.eval_sets(sets)
+}
+
+/// Evaluate sets given as sorted iterators depending on the formula, lazily.
+/// The inputs are read once and in step, so they can be larger than the memory,
+/// like the lines of sorted files. The negation is the complement in the union
+/// of all sets and the elements are given in ascending order
+/// # Arguments
+/// * `formula` - The formula
+/// * `sets` - The sets of the vars in alphabetical order, each one in strictly ascending order
+/// # Example
+/// ```
+/// use ex09::eval_set_sorted;
+///
+/// let evens = (0..).step_by(2).take(1000);
+/// let threes = (0..).step_by(3).take(1000);
+/// let mut res = eval_set_sorted("AB&", vec![evens, threes]);
+///
+/// assert_eq!(Some(0), res.next());
+/// assert_eq!(Some(6), res.next());
+/// assert_eq!(Some(12), res.next());
+/// ```
+/// # Panics
+/// If the formula is invalid, or while iterating, when a set is not sorted or has
+/// duplicate elements
+pub fn eval_set_sorted<T: Ord, I: IntoIterator<Item = T>>(
+    formula: &str,
+    sets: Vec<I>,
+) -> SortedSetEval<T, I::IntoIter> {
+    if formula.is_empty() || formula.contains(['+', '-']) {
+        panic!("Invalid formula");
+    }
+    let mut tree: AST = AST::new();
+
+    tree.build(formula, true);
+    let program: Vec<ast::Step> = tree.get_program(sets.len());
+
+    SortedSetEval::new(sets.into_iter().map(|set: I| set.into_iter()).collect(), program)
+}
+
+fn eval_set_in<T: Ord + Clone>(formula: &str, sets: Vec<Vec<T>>, universe: Option<Vec<T>>, engine: Engine) -> Vec<T> {
+    // the sum and the difference are only defined on multisets
+    if formula.is_empty() || formula.contains(['+', '-']) {
+        panic!("Invalid formula");
+    }
+    let mut tree: AST = AST::new();
+
+    tree.build(formula, true);
+    match engine {
+        Engine::HashSet => tree.eval::<T, IndexHashSet>(sets, universe),
+        Engine::BitSet => tree.eval::<T, BitSet>(sets, universe),
+        Engine::Roaring => tree.eval::<T, RoaringBitmap>(sets, universe),
+        Engine::Intervals => tree.eval::<T, IntervalSet>(sets, universe),
+    }
+}
+
+/// Evaluate multisets depending on the formula, the sets may hold the same element
+/// several times and the result holds each element as many times as its multiplicity:
+/// * `&` - The intersection, the minimum of the multiplicities
+/// * `|` - The union, the maximum of the multiplicities
+/// * `+` - The sum of the multiplicities
+/// * `-` - The difference of the multiplicities, down to 0
+/// * `^` - The absolute difference of the multiplicities
+/// # Example
+/// ```
+/// use ex09::eval_multiset;
+///
+/// assert_eq!(vec![1, 2, 2], eval_multiset("AB&", vec![vec![1, 1, 2, 2], vec![2, 2, 1, 3]]));
+/// assert_eq!(vec![1, 1, 1, 2], eval_multiset("AB+", vec![vec![1, 1], vec![1, 2]]));
+/// ```
+/// # Panics
+/// If the formula is invalid, or if it uses the negation or an operator
+/// other than the ones above, since a multiset has no complement
+pub fn eval_multiset<T: Ord + Clone>(formula: &str, sets: Vec<Vec<T>>) -> Vec<T> {
     if formula.is_empty() {
         panic!("Invalid formula");
     }
     let mut tree: AST = AST::new();
 
     tree.build(formula, true);
-    tree.simplify_material_properties();
-    tree.eval(sets)
+    tree.eval_multiset(sets)
+}
+
+/// Synthesize a formula into a structural Verilog module,
+/// see netlist::verilog_netlist
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// * `module` - The name of the module
+/// # Panics
+/// If the formula is invalid
+pub fn verilog_netlist(formula: &str, module: &str) -> String {
+    netlist::verilog_netlist(formula, module)
+}
+
+/// Synthesize a formula into a BLIF model, see netlist::blif_netlist
+/// # Arguments
+/// * `formula` - A string slice that holds the formula
+/// * `model` - The name of the model
+/// # Panics
+/// If the formula is invalid
+pub fn blif_netlist(formula: &str, model: &str) -> String {
+    netlist::blif_netlist(formula, model)
 }
 
 #[cfg(test)]
@@ -345,6 +540,26 @@ mod tests {
         assert_eq!(vec![-10, 0, 2, 3, 5, 20], res);
     }
 
+    #[test]
+    fn sets_functional_operators_tests() {
+        let sets: Vec<Vec<i32>> = vec![vec![-10, 3, 20, 2, 0, 5], vec![2, 0, -10, 13, 60, 5]];
+        let mut res: Vec<i32> = eval_set("AB↑", sets.clone());
+        res.sort();
+        assert_eq!(vec![3, 13, 20, 60], res);
+
+        res = eval_set("AB↓", sets.clone());
+        res.sort();
+        assert_eq!(vec![] as Vec<i32>, res);
+
+        res = eval_set("AB⊙", sets.clone());
+        res.sort();
+        assert_eq!(vec![-10, 0, 2, 5], res);
+
+        res = eval_set("AB↑!", sets);
+        res.sort();
+        assert_eq!(vec![-10, 0, 2, 5], res);
+    }
+
     #[test]
     fn sets_mixed_tests() {
         let mut res: Vec<i32> = eval_set(
@@ -491,4 +706,323 @@ mod tests {
     fn invalid_formula_unknown_symbol_test4() {
         eval_set("111|", vec![] as Vec<Vec<i32>>);
     }
+
+    #[test]
+    #[should_panic(expected = "Invalid formula")]
+    fn invalid_formula_multiset_operator_test() {
+        eval_set("AB+", vec![vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn multiset_tests() {
+        let bags: Vec<Vec<i32>> = vec![vec![3, 1, 1, 2, 1], vec![1, 2, 2, 4]];
+
+        assert_eq!(vec![1, 2], eval_multiset("AB&", bags.clone()));
+        assert_eq!(vec![1, 1, 1, 2, 2, 3, 4], eval_multiset("AB|", bags.clone()));
+        assert_eq!(vec![1, 1, 1, 1, 2, 2, 2, 3, 4], eval_multiset("AB+", bags.clone()));
+        assert_eq!(vec![1, 1, 3], eval_multiset("AB-", bags.clone()));
+        assert_eq!(vec![2, 4], eval_multiset("BA-", bags.clone()));
+        assert_eq!(vec![1, 1, 2, 3, 4], eval_multiset("AB^", bags.clone()));
+        assert_eq!(vec![1, 1, 1, 1, 1, 2, 3, 3], eval_multiset("AA+B-A|", bags.clone()));
+        assert_eq!(vec![] as Vec<i32>, eval_multiset("AB&C&", vec![vec![1, 1], vec![1], vec![]]));
+        // without duplicates the intersection and the union are the ones of the sets
+        let sets: Vec<Vec<i32>> = vec![vec![0, 1, 2], vec![0, 3, 4], vec![5, 2]];
+
+        for formula in ["AB&C|", "AB|C&", "ABC&|"] {
+            let mut res: Vec<i32> = eval_set(formula, sets.clone());
+            res.sort();
+            assert_eq!(res, eval_multiset(formula, sets.clone()));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid multiset operator")]
+    fn multiset_negation_test() {
+        eval_multiset("AB&!", vec![vec![1, 1], vec![1]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid multiset operator")]
+    fn multiset_material_condition_test() {
+        eval_multiset("AB>", vec![vec![1, 1], vec![1]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "The number of sets should be equal to the number of vars in the formula")]
+    fn multiset_number_of_sets_test() {
+        eval_multiset("AB+", vec![vec![1, 1]]);
+    }
+
+    #[test]
+    fn generic_sets_tests() {
+        let names: Vec<Vec<String>> = vec![
+            vec!["alice".to_string(), "bob".to_string()],
+            vec!["carol".to_string(), "bob".to_string()],
+        ];
+
+        assert_eq!(vec!["bob".to_string()], eval_set("AB&", names.clone()));
+        assert_eq!(vec!["alice".to_string(), "carol".to_string()], eval_set("AB^", names));
+        assert_eq!(vec!['x', 'z'], eval_set("AB|", vec![vec!['z'], vec!['x']]));
+        assert_eq!(vec![-3i64], eval_set("AB!&", vec![vec![-3i64, 7], vec![7, 9]]));
+    }
+
+    #[test]
+    fn universe_tests() {
+        let universe: Vec<u32> = (1..=6).collect();
+
+        assert_eq!(vec![] as Vec<i32>, eval_set("A!", vec![vec![0, 1, 2]]));
+        assert_eq!(vec![3, 4, 5, 6], eval_set_with_universe("A!", vec![vec![1, 2]], universe.clone()));
+        assert_eq!(vec![1, 2, 6], eval_set_with_universe("AB|!", vec![vec![3, 4], vec![5]], universe.clone()));
+        assert_eq!(vec![1, 2, 3, 4, 6], eval_set_with_universe("A!B!|", vec![vec![3, 5], vec![5, 4]], universe.clone()));
+        assert_eq!(universe.clone(), eval_set_with_universe("AA!|", vec![vec![]], universe.clone()));
+        assert_eq!(vec![2], eval_set_with_universe("AB>", vec![vec![1, 2], vec![2]], vec![1, 2]));
+        // the universe doesn't change the formulas without negation
+        let sets: Vec<Vec<u32>> = vec![vec![1, 3], vec![3, 4], vec![6]];
+
+        for formula in ["AB&C|", "AB^C|", "AB|C&"] {
+            assert_eq!(eval_set(formula, sets.clone()), eval_set_with_universe(formula, sets.clone(), universe.clone()));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Set with index 1 has elements outside of the universal set")]
+    fn set_outside_of_universe_test() {
+        eval_set_with_universe("AB|", vec![vec![1], vec![2, 7]], vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn set_env_tests() {
+        let env: HashMap<String, Vec<i32>> = HashMap::from([
+            ("A".to_string(), vec![1, 2, 3]),
+            ("C".to_string(), vec![3, 4]),
+        ]);
+
+        // C is bound by its name and not by its position
+        assert_eq!(vec![3], eval_set_env("AC&", env.clone()));
+        assert_eq!(vec![1, 2], eval_set_env("CA!|!", env.clone()));
+        assert_eq!(vec![1, 2, 4], eval_set_env("{A}{C}^", env));
+
+        let env: HashMap<String, Vec<&str>> = HashMap::from([
+            ("users".to_string(), vec!["ann", "bob", "eve"]),
+            ("admins".to_string(), vec!["bob"]),
+            ("banned_2".to_string(), vec!["eve"]),
+            ("Z".to_string(), vec![]),
+        ]);
+
+        assert_eq!(vec!["ann"], eval_set_env("{users}{admins}!&{banned_2}!&Z|", env.clone()));
+        assert_eq!(vec!["bob", "eve"], eval_set_env("{admins}{banned_2}|{users}&Z|", env));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unbound var admins")]
+    fn set_env_unbound_var_test() {
+        eval_set_env("A{admins}&", HashMap::from([("A".to_string(), vec![1])]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unused var B")]
+    fn set_env_unused_var_test() {
+        eval_set_env(
+            "AC&",
+            HashMap::from([("A".to_string(), vec![1]), ("B".to_string(), vec![2]), ("C".to_string(), vec![3])]),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid var name {a b}")]
+    fn set_env_invalid_name_test() {
+        eval_set_env("{a b}A&", HashMap::from([("A".to_string(), vec![1])]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid formula")]
+    fn set_env_unterminated_name_test() {
+        eval_set_env("A{admins&", HashMap::from([("A".to_string(), vec![1])]));
+    }
+
+    /// A xorshift generator to get reproducible random inputs
+    fn xorshift(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    #[test]
+    fn engines_tests() {
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        // sparse and dense sets, over several chunks of the roaring bitmaps
+        let sets: Vec<Vec<u32>> = [3, 50, 97]
+            .iter()
+            .map(|density: &u64| {
+                (0..100_000).filter(|_| xorshift(&mut state) % 100 < *density).collect()
+            })
+            .collect();
+
+        for formula in ["AB&C|", "AB|!C&", "ABC&|!", "AB^C=", "A!B>C|", "AA!&BC&|"] {
+            let expected: Vec<u32> = eval_set_with_engine(formula, sets.clone(), Engine::HashSet);
+
+            assert_eq!(expected, eval_set_with_engine(formula, sets.clone(), Engine::BitSet));
+            assert_eq!(expected, eval_set_with_engine(formula, sets.clone(), Engine::Roaring));
+            assert_eq!(expected, eval_set_with_engine(formula, sets.clone(), Engine::Intervals));
+        }
+        assert_eq!(eval_set("AB&C|", sets.clone()), eval_set_with_engine("AB&C|", sets, Engine::HashSet));
+    }
+
+    #[test]
+    fn set_ops_tests() {
+        let indexes: Vec<usize> = vec![0, 63, 64, 65_535, 65_536, 70_000, 130_000];
+
+        fn check<S: SetOps>(indexes: &[usize]) {
+            let set: S = S::from_indexes(indexes, 130_001);
+            let complement: S = set.complement();
+
+            assert_eq!(indexes.to_vec(), set.to_indexes());
+            assert_eq!(130_001 - indexes.len(), complement.to_indexes().len());
+            assert_eq!(130_001, set.union(&complement).to_indexes().len());
+            assert!(set.intersection(&complement).to_indexes().is_empty());
+            assert_eq!(indexes.to_vec(), complement.complement().to_indexes());
+            assert_eq!(Vec::<usize>::new(), S::from_indexes(&[], 0).complement().to_indexes());
+            assert_eq!(vec![0, 1, 2], S::from_indexes(&[], 3).complement().to_indexes());
+        }
+
+        check::<IndexHashSet>(&indexes);
+        check::<BitSet>(&indexes);
+        check::<RoaringBitmap>(&indexes);
+        check::<IntervalSet>(&indexes);
+    }
+
+    type BoolOp = fn(bool, bool) -> bool;
+    type FormulaEval = fn(BoolOp, bool, bool, bool) -> bool;
+
+    #[test]
+    fn native_operators_tests() {
+        let sets: Vec<Vec<i32>> = vec![vec![1, 2, 5, 6], vec![2, 3, 6, 7], vec![4, 5, 6, 7]];
+        let universe: Vec<i32> = (0..8).collect();
+        let ops: [(&str, BoolOp); 8] = [
+            ("&", |a, b| a & b),
+            ("|", |a, b| a | b),
+            ("^", |a, b| a ^ b),
+            (">", |a, b| !a | b),
+            ("=", |a, b| a == b),
+            ("↑", |a, b| !(a & b)),
+            ("↓", |a, b| !(a | b)),
+            ("⊙", |a, b| a == b),
+        ];
+
+        // each element is in the result if the formula is true on its membership of the sets
+        for (op, eval) in ops.iter() {
+            let formulas: [(String, FormulaEval); 3] = [
+                (format!("AB{op}C{op}"), |eval, a, b, c| eval(eval(a, b), c)),
+                (format!("ABC{op}{op}"), |eval, a, b, c| eval(a, eval(b, c))),
+                (format!("AB{op}!C!{op}!!"), |eval, a, b, c| eval(!eval(a, b), !c)),
+            ];
+
+            for (formula, formula_eval) in formulas.iter() {
+                let expected: Vec<i32> = universe
+                    .iter()
+                    .copied()
+                    .filter(|x: &i32| formula_eval(*eval, sets[0].contains(x), sets[1].contains(x), sets[2].contains(x)))
+                    .collect();
+
+                for engine in [Engine::HashSet, Engine::BitSet, Engine::Roaring, Engine::Intervals] {
+                    let res: Vec<i32> = eval_set_in(formula, sets.clone(), Some(universe.clone()), engine);
+
+                    assert_eq!(expected, res, "{formula} with {engine:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sorted_output_tests() {
+        // the results are sorted without calling sort
+        assert_eq!(vec![-8, 0, 4, 9], eval_set("AB|", vec![vec![9, 0], vec![4, -8]]));
+        assert_eq!(vec!["a", "c", "d"], eval_set("AB^", vec![vec!["d", "b"], vec!["b", "c", "a"]]));
+    }
+
+    #[test]
+    fn sorted_stream_tests() {
+        let sets: Vec<Vec<i32>> = vec![vec![-4, 0, 1, 5, 8], vec![0, 2, 5, 9], vec![1, 2, 3, 5]];
+
+        for formula in ["AB&C|", "AB|C&", "AB!&C|", "AB^C|", "ABC>=", "AB↑C↓", "A!!B⊙C!&", "AB&C!|!"] {
+            let res: Vec<i32> = eval_set_sorted(formula, sets.clone()).collect();
+
+            assert_eq!(eval_set(formula, sets.clone()), res, "{formula}");
+        }
+        // lazy on infinite inputs, the union of the multiples of 4 and 6 without the ones of 3
+        let res: Vec<u64> = eval_set_sorted("AB|C!&", vec![(0..).step_by(4), (0..).step_by(6), (0..).step_by(3)])
+            .take(5)
+            .collect();
+
+        assert_eq!(vec![4, 8, 16, 20, 28], res);
+        // the inputs are only read in step
+        let lines = "apple\nbanana\ncherry\n".lines();
+        let other = "banana\nfig\n".lines();
+
+        assert_eq!(vec!["apple", "cherry"], eval_set_sorted("AB!&", vec![lines, other]).collect::<Vec<&str>>());
+        assert_eq!(0, eval_set_sorted("AB&", vec![Vec::<i32>::new(), vec![1]]).count());
+    }
+
+    #[test]
+    #[should_panic(expected = "Set with index 1 is not sorted")]
+    fn sorted_stream_unsorted_test() {
+        eval_set_sorted("AB|", vec![vec![1, 2, 3], vec![2, 1]]).for_each(drop);
+    }
+
+    #[test]
+    #[should_panic(expected = "Set with index 0 has duplicate elements")]
+    fn sorted_stream_duplicate_test() {
+        eval_set_sorted("AB|", vec![vec![1, 2, 2], vec![3]]).for_each(drop);
+    }
+
+    #[test]
+    fn interval_sets_tests() {
+        let bounds = 0..=99;
+        let a: IntervalSet = IntervalSet::from_ranges(&[10..=19, 40..=59], bounds.clone());
+        let b: IntervalSet = IntervalSet::from_ranges(&[15..=44, 90..=99], bounds.clone());
+        let c: IntervalSet = IntervalSet::from_values(&[0, 1, 2, 50, 52], bounds.clone());
+
+        assert_eq!(vec![15..=19, 40..=44], eval_set_intervals("AB&", vec![a.clone(), b.clone()]).get_ranges());
+        assert_eq!(vec![10..=59, 90..=99], eval_set_intervals("AB|", vec![a.clone(), b.clone()]).get_ranges());
+        assert_eq!(vec![10..=14, 20..=39, 45..=59, 90..=99], eval_set_intervals("AB^", vec![a.clone(), b.clone()]).get_ranges());
+        assert_eq!(vec![0..=9, 20..=39, 60..=99], eval_set_intervals("A!", vec![a.clone()]).get_ranges());
+        assert_eq!(
+            vec![0..=2, 10..=14, 45..=49, 51..=51, 53..=59],
+            eval_set_intervals("AB!&C^", vec![a.clone(), b.clone(), c.clone()]).get_ranges()
+        );
+        // the same results as on the explicit values
+        for formula in ["AB&C|", "AB>C=", "A!BC↑↓", "AB|C!&"] {
+            let expected: Vec<i32> = eval_set_with_universe(
+                formula,
+                vec![a.to_values(), b.to_values(), c.to_values()],
+                (0..100).collect(),
+            );
+            let res: IntervalSet = eval_set_intervals(formula, vec![a.clone(), b.clone(), c.clone()]);
+
+            assert_eq!(expected, res.to_values(), "{formula}");
+            assert_eq!(expected.len() as u64, res.len());
+            assert!(expected.iter().all(|value: &i32| res.contains(*value)));
+        }
+        assert!(!a.contains(20) && a.contains(19) && !a.contains(-1));
+        assert!(IntervalSet::new(bounds.clone()).is_empty());
+        assert_eq!(vec![0..=99], IntervalSet::new(bounds).complement().get_ranges());
+        // the whole range of i32 holds 2 ^ 32 values
+        let full: IntervalSet = IntervalSet::new(i32::MIN..=i32::MAX).complement();
+
+        assert_eq!(1 << 32, full.len());
+        assert_eq!(vec![i32::MIN..=-1, 1..=i32::MAX], IntervalSet::from_values(&[0], i32::MIN..=i32::MAX).complement().get_ranges());
+    }
+
+    #[test]
+    #[should_panic(expected = "Set with index 1 has different bounds")]
+    fn interval_sets_bounds_test() {
+        eval_set_intervals("AB|", vec![IntervalSet::new(0..=10), IntervalSet::new(0..=11)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Range 5..=12 is outside of the bounds")]
+    fn interval_set_outside_of_bounds_test() {
+        IntervalSet::from_ranges(&[0..=1, 5..=12], 0..=10);
+    }
 }
@@ -0,0 +1,84 @@
+use crate::ast::Step;
+use std::iter::Peekable;
+
+/// A lazy evaluation of a formula on sets given as sorted iterators. The inputs are
+/// merged as in a merge sort, and each element is kept if the formula is true on
+/// the sets it belongs to, so only the head of each input is held in memory
+pub struct SortedSetEval<T: Ord, I: Iterator<Item = T>> {
+    inputs: Vec<Peekable<I>>,
+    program: Vec<Step>,
+    membership: Vec<bool>,
+    stack: Vec<bool>,
+}
+
+impl<T: Ord, I: Iterator<Item = T>> SortedSetEval<T, I> {
+    /// Get a new evaluation of the formula steps on the inputs
+    pub(crate) fn new(inputs: Vec<I>, program: Vec<Step>) -> Self {
+        Self {
+            membership: vec![false; inputs.len()],
+            inputs: inputs.into_iter().map(|input: I| input.peekable()).collect(),
+            program,
+            stack: Vec::new(),
+        }
+    }
+
+    /// Evaluate the formula on the membership of the current element
+    fn eval_membership(&mut self) -> bool {
+        self.stack.clear();
+        for step in self.program.iter() {
+            let value: bool = match step {
+                Step::Var(i) => self.membership[*i],
+                Step::Not => !self.stack.pop().unwrap(),
+                _ => {
+                    let b: bool = self.stack.pop().unwrap();
+                    let a: bool = self.stack.pop().unwrap();
+
+                    match step {
+                        Step::And => a & b,
+                        Step::Or => a | b,
+                        Step::Xor => a ^ b,
+                        Step::MatCond => !a | b,
+                        Step::LogEq => a == b,
+                        Step::Nand => !(a & b),
+                        _ => !(a | b),
+                    }
+                }
+            };
+
+            self.stack.push(value);
+        }
+
+        self.stack.pop().unwrap()
+    }
+}
+
+impl<T: Ord, I: Iterator<Item = T>> Iterator for SortedSetEval<T, I> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            // the next element of the union is the smallest head of the inputs
+            let first: usize = self
+                .inputs
+                .iter_mut()
+                .enumerate()
+                .filter_map(|(i, input)| input.peek().map(|head: &T| (head, i)))
+                .min()?
+                .1;
+            let value: T = self.inputs[first].next().unwrap();
+
+            for (i, input) in self.inputs.iter_mut().enumerate() {
+                self.membership[i] = i == first || input.next_if_eq(&value).is_some();
+                if input.peek().is_some_and(|next: &T| *next <= value) {
+                    if *input.peek().unwrap() == value {
+                        panic!("Set with index {i} has duplicate elements");
+                    }
+                    panic!("Set with index {i} is not sorted");
+                }
+            }
+            if self.eval_membership() {
+                return Some(value);
+            }
+        }
+    }
+}
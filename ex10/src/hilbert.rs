@@ -0,0 +1,195 @@
+use crate::range;
+
+/// Encode N-dimensional coordinates into their index on the Hilbert curve
+/// # Arguments
+/// * `coords` - The coordinates, each one holding `bits` bits
+/// * `bits` - The number of bits of each coordinate, the curve fills a grid of side 2 ^ bits
+/// # Panics
+/// If `bits` is not in [1, 32], if the index doesn't fit in 128 bits or if a coordinate
+/// doesn't fit in `bits` bits
+/// # Examples
+/// ```
+/// use ex10::hilbert::encode;
+///
+/// // the first order curve visits (0, 0), (0, 1), (1, 1) and (1, 0)
+/// assert_eq!(0, encode(&[0, 0], 1));
+/// assert_eq!(1, encode(&[0, 1], 1));
+/// assert_eq!(2, encode(&[1, 1], 1));
+/// assert_eq!(3, encode(&[1, 0], 1));
+/// ```
+pub fn encode(coords: &[u32], bits: u32) -> u128 {
+    check_dimensions(coords.len(), bits);
+    if coords.iter().any(|c: &u32| (*c as u64) >> bits != 0) {
+        panic!("Invalid coordinate");
+    }
+    let mut transposed: Vec<u32> = coords.to_vec();
+
+    axes_to_transpose(&mut transposed, bits);
+    interleave(&transposed, bits)
+}
+
+/// Decode an index on the Hilbert curve into its N-dimensional coordinates
+/// # Arguments
+/// * `index` - The index on the curve
+/// * `dims` - The number of dimensions
+/// * `bits` - The number of bits of each coordinate
+/// # Panics
+/// If `bits` is not in [1, 32], if `dims * bits` is over 128 or if the index doesn't fit
+/// in `dims * bits` bits
+/// # Examples
+/// ```
+/// use ex10::hilbert::{decode, encode};
+///
+/// assert_eq!(vec![1, 0], decode(3, 2, 1));
+/// assert_eq!(vec![5, 9, 2], decode(encode(&[5, 9, 2], 4), 3, 4));
+/// ```
+pub fn decode(index: u128, dims: usize, bits: u32) -> Vec<u32> {
+    check_dimensions(dims, bits);
+    if dims * (bits as usize) < 128 && index >> (dims * bits as usize) != 0 {
+        panic!("Invalid index");
+    }
+    let mut res: Vec<u32> = deinterleave(index, dims, bits);
+
+    transpose_to_axes(&mut res, bits);
+    res
+}
+
+/// Get the intervals of the Hilbert curve covering the box [min, max],
+/// the intervals are sorted, inclusive and merged when they are adjacent
+/// # Arguments
+/// * `min` - The lowest corner of the box
+/// * `max` - The highest corner of the box
+/// * `bits` - The number of bits of each coordinate
+/// # Panics
+/// If the corners don't have the same number of dimensions, if there are more than 16 of
+/// them, if min > max, if the box is out of the grid or if it is split into more than
+/// 65536 blocks
+/// # Examples
+/// ```
+/// use ex10::hilbert::range_query;
+///
+/// // the bottom row of the 4 x 4 grid
+/// assert_eq!(vec![(0, 1), (14, 15)], range_query(&[0, 0], &[3, 0], 2));
+/// ```
+pub fn range_query(min: &[u32], max: &[u32], bits: u32) -> Vec<(u128, u128)> {
+    check_dimensions(min.len(), bits);
+
+    range::decompose(min, max, bits, |coords: &[u32]| encode(coords, bits))
+}
+
+fn check_dimensions(dims: usize, bits: u32) {
+    if bits == 0 || bits > 32 {
+        panic!("Invalid number of bits");
+    }
+    if dims == 0 || dims * bits as usize > 128 {
+        panic!("Invalid dimension");
+    }
+}
+
+fn axes_to_transpose(x: &mut [u32], bits: u32) {
+    /*
+        Skilling's algorithm, the coordinates are transformed in place into the "transposed"
+        index: the bit b of the index word i is the bit (b * dims + i) of the index counted
+        from the most significant one.
+        1- undo the rotations and reflections done at each level of the curve,
+        from the highest bit to the lowest one
+        2- Gray encode the words, the Hilbert curve visits the sub-cubes in the
+        order of the Gray code: 00, 01, 11, 10 (see ex02 gray_code)
+     */
+    let n: usize = x.len();
+    let m: u32 = 1 << (bits - 1);
+    let mut q: u32 = m;
+
+    while q > 1 {
+        let p: u32 = q - 1;
+
+        for i in 0..n {
+            if x[i] & q != 0 {
+                // invert the low bits of the first word
+                x[0] ^= p;
+            } else {
+                // exchange the low bits of the first word and the word i
+                let t: u32 = (x[0] ^ x[i]) & p;
+
+                x[0] ^= t;
+                x[i] ^= t;
+            }
+        }
+        q >>= 1;
+    }
+    // Gray encode
+    for i in 1..n {
+        x[i] ^= x[i - 1];
+    }
+    let mut t: u32 = 0;
+
+    q = m;
+    while q > 1 {
+        if x[n - 1] & q != 0 {
+            t ^= q - 1;
+        }
+        q >>= 1;
+    }
+    for word in x.iter_mut() {
+        *word ^= t;
+    }
+}
+
+fn transpose_to_axes(x: &mut [u32], bits: u32) {
+    // this is the exact inverse of axes_to_transpose
+    let n: usize = x.len();
+    let end: u64 = 2 << (bits - 1);
+
+    // Gray decode
+    let t: u32 = x[n - 1] >> 1;
+
+    for i in (1..n).rev() {
+        x[i] ^= x[i - 1];
+    }
+    x[0] ^= t;
+    // redo the rotations and reflections, from the lowest bit to the highest one
+    let mut q: u64 = 2;
+
+    while q != end {
+        let p: u32 = (q - 1) as u32;
+
+        for i in (0..n).rev() {
+            if x[i] as u64 & q != 0 {
+                x[0] ^= p;
+            } else {
+                let t: u32 = (x[0] ^ x[i]) & p;
+
+                x[0] ^= t;
+                x[i] ^= t;
+            }
+        }
+        q <<= 1;
+    }
+}
+
+fn interleave(x: &[u32], bits: u32) -> u128 {
+    let mut res: u128 = 0;
+
+    // the highest bits of the words come first, the first word holds the most significant bit
+    for b in (0..bits).rev() {
+        for word in x.iter() {
+            res = (res << 1) | ((word >> b) & 1) as u128;
+        }
+    }
+
+    res
+}
+
+fn deinterleave(index: u128, dims: usize, bits: u32) -> Vec<u32> {
+    let mut res: Vec<u32> = vec![0; dims];
+    let mut pos: u32 = dims as u32 * bits;
+
+    for b in (0..bits).rev() {
+        for word in res.iter_mut() {
+            pos -= 1;
+            *word |= (((index >> pos) & 1) as u32) << b;
+        }
+    }
+
+    res
+}
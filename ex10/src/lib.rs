@@ -0,0 +1,295 @@
+pub mod hilbert;
+pub mod morton;
+mod range;
+
+/// Map the coordinates (x, y) to a unique value in [0, 1] using the Z-order curve
+/// # Arguments
+/// * `x` - The x coordinate
+/// * `y` - The y coordinate
+/// # Examples
+/// ```
+/// use ex10::map;
+///
+/// assert_eq!(0.0, map(0, 0));
+/// assert_eq!(1.0, map(u16::MAX, u16::MAX));
+/// assert_eq!(3.0 / u32::MAX as f64, map(1, 1));
+/// ```
+pub fn map(x: u16, y: u16) -> f64 {
+    let mut res: u32 = 0;
+
+    for i in 0..16 {
+        /*
+            interleave the bits of x and y, the bit at index i
+            of x goes to the index 2 * i of the result and the bit
+            at index i of y goes to the index 2 * i + 1:
+            x = 0b11, y = 0b00 => res = 0b0101
+        */
+        if (x & (1 << i)) != 0 {
+            res |= 1 << (2 * i);
+        }
+        if (y & (1 << i)) != 0 {
+            res |= 1 << (2 * i + 1);
+        }
+    }
+
+    /*
+        every u32 is exactly representable as an f64,
+        so dividing by u32::MAX keeps the mapping reversible
+    */
+    res as f64 / u32::MAX as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_index(x: u16, y: u16) -> u32 {
+        (map(x, y) * u32::MAX as f64).round() as u32
+    }
+
+    #[test]
+    fn subject_tests() {
+        assert_eq!(0.0, map(0, 0));
+        assert_eq!(1.0, map(u16::MAX, u16::MAX));
+        assert!(map(12, 34) >= 0.0 && map(12, 34) <= 1.0);
+    }
+
+    #[test]
+    fn interleave_tests() {
+        assert_eq!(0b01, get_index(1, 0));
+        assert_eq!(0b10, get_index(0, 1));
+        assert_eq!(0b11, get_index(1, 1));
+        assert_eq!(0b0100, get_index(2, 0));
+        assert_eq!(0b1000, get_index(0, 2));
+        assert_eq!(0x5555_5555, get_index(u16::MAX, 0));
+        assert_eq!(0xAAAA_AAAA, get_index(0, u16::MAX));
+        assert_eq!(0b1101, get_index(3, 2));
+    }
+
+    #[test]
+    fn z_order_tests() {
+        // the Z-order visits the four cells of each square in the order (0,0), (1,0), (0,1), (1,1)
+        assert!(map(0, 0) < map(1, 0));
+        assert!(map(1, 0) < map(0, 1));
+        assert!(map(0, 1) < map(1, 1));
+        assert!(map(1, 1) < map(2, 0));
+    }
+
+    #[test]
+    fn injective_tests() {
+        let mut values: Vec<u32> = Vec::new();
+
+        for x in 0..=255 {
+            for y in 0..=255 {
+                values.push(get_index(x, y));
+            }
+        }
+        values.sort();
+        values.dedup();
+        assert_eq!(256 * 256, values.len());
+        // the 256 * 256 square is mapped to the first 2 ^ 16 values
+        assert_eq!(Some(&0xFFFF), values.last());
+    }
+
+    #[test]
+    fn morton_encode_tests() {
+        assert_eq!(0, morton::encode(&[0, 0]));
+        assert_eq!(0b1101, morton::encode(&[3, 2]));
+        assert_eq!(0x5555_5555, morton::encode(&[u32::MAX >> 16, 0]));
+        assert_eq!(u64::MAX as u128, morton::encode(&[u32::MAX, u32::MAX]));
+        assert_eq!(u128::MAX, morton::encode(&[u32::MAX; 4]));
+        assert_eq!(u32::MAX as u128, morton::encode(&[u32::MAX]));
+        assert_eq!(0b100_010_001, morton::encode_3d(1, 2, 4));
+        assert_eq!(1 << 95, morton::encode_3d(0, 0, 1 << 31));
+    }
+
+    #[test]
+    fn morton_round_trip_tests() {
+        for x in 0..16 {
+            for y in 0..16 {
+                for z in 0..16 {
+                    assert_eq!((x, y, z), morton::decode_3d(morton::encode_3d(x, y, z)));
+                }
+            }
+        }
+        for dims in 1..=4 {
+            let coords: Vec<u32> = (0..dims as u32).map(|d: u32| u32::MAX - d * 12_345).collect();
+
+            assert_eq!(coords, morton::decode(morton::encode(&coords), dims));
+        }
+    }
+
+    #[test]
+    fn morton_range_query_tests() {
+        assert_eq!(vec![(0, 15)], morton::range_query(&[0, 0], &[3, 3]));
+        assert_eq!(vec![(0, 0)], morton::range_query(&[0, 0], &[0, 0]));
+        assert_eq!(vec![(0, 63)], morton::range_query(&[0, 0, 0], &[3, 3, 3]));
+        assert_eq!(vec![(0, u64::MAX as u128)], morton::range_query(&[0, 0], &[u32::MAX, u32::MAX]));
+
+        // the intervals hold exactly the cells of the box
+        let (min, max): ([u32; 2], [u32; 2]) = ([1, 2], [6, 5]);
+        let intervals: Vec<(u128, u128)> = morton::range_query(&min, &max);
+        let mut cells: Vec<u128> = Vec::new();
+
+        for x in min[0]..=max[0] {
+            for y in min[1]..=max[1] {
+                cells.push(morton::encode(&[x, y]));
+            }
+        }
+        cells.sort();
+        assert_eq!(
+            cells,
+            intervals.iter().flat_map(|(lo, hi)| *lo..=*hi).collect::<Vec<u128>>()
+        );
+        assert!(intervals.windows(2).all(|w| w[0].1 + 1 < w[1].0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid dimension")]
+    fn morton_invalid_dimension_test1() {
+        morton::encode(&[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid dimension")]
+    fn morton_invalid_dimension_test2() {
+        morton::decode(0, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid range")]
+    fn morton_invalid_range_test() {
+        morton::range_query(&[2, 0], &[1, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Too many intervals")]
+    fn morton_too_many_intervals_test() {
+        morton::range_query(&[0, 0], &[u32::MAX, 0]);
+    }
+
+    #[test]
+    fn hilbert_first_order_tests() {
+        let cells: Vec<Vec<u32>> = (0..4).map(|i: u128| hilbert::decode(i, 2, 1)).collect();
+
+        assert_eq!(vec![vec![0, 0], vec![0, 1], vec![1, 1], vec![1, 0]], cells);
+        let cells: Vec<Vec<u32>> = (0..16).map(|i: u128| hilbert::decode(i, 2, 2)).collect();
+
+        // the second order curve visits the four quadrants in the same order, each one rotated
+        assert_eq!(
+            vec![
+                vec![0, 0], vec![1, 0], vec![1, 1], vec![0, 1],
+                vec![0, 2], vec![0, 3], vec![1, 3], vec![1, 2],
+                vec![2, 2], vec![2, 3], vec![3, 3], vec![3, 2],
+                vec![3, 1], vec![2, 1], vec![2, 0], vec![3, 0],
+            ],
+            cells
+        );
+    }
+
+    #[test]
+    fn hilbert_round_trip_tests() {
+        for (dims, bits) in [(2, 4), (3, 3), (4, 2), (1, 8)] {
+            let size: u128 = 1 << (dims as u32 * bits);
+
+            for i in 0..size {
+                let coords: Vec<u32> = hilbert::decode(i, dims, bits);
+
+                assert!(coords.iter().all(|c: &u32| (*c as u64) < 1 << bits));
+                assert_eq!(i, hilbert::encode(&coords, bits));
+            }
+        }
+        let coords: [u32; 4] = [u32::MAX, 0, 123_456_789, 1 << 31];
+
+        assert_eq!(coords.to_vec(), hilbert::decode(hilbert::encode(&coords, 32), 4, 32));
+        assert_eq!(vec![u32::MAX, u32::MAX], hilbert::decode(hilbert::encode(&[u32::MAX, u32::MAX], 32), 2, 32));
+    }
+
+    #[test]
+    fn hilbert_locality_tests() {
+        // two consecutive cells of the curve are always neighbours, unlike on the Z-order curve
+        for (dims, bits) in [(2, 5), (3, 3), (4, 2)] {
+            let size: u128 = 1 << (dims as u32 * bits);
+            let mut prev: Vec<u32> = hilbert::decode(0, dims, bits);
+
+            for i in 1..size {
+                let curr: Vec<u32> = hilbert::decode(i, dims, bits);
+                let distance: u32 = prev.iter().zip(curr.iter()).map(|(a, b)| a.abs_diff(*b)).sum();
+
+                assert_eq!(1, distance);
+                prev = curr;
+            }
+        }
+        assert_eq!(3, morton::encode(&[1, 1]) - morton::encode(&[0, 0]));
+    }
+
+    #[test]
+    fn hilbert_range_query_tests() {
+        assert_eq!(vec![(0, 15)], hilbert::range_query(&[0, 0], &[3, 3], 2));
+        assert_eq!(vec![(0, 255)], hilbert::range_query(&[0, 0], &[15, 15], 4));
+        assert_eq!(vec![(2, 2)], hilbert::range_query(&[1, 1], &[1, 1], 1));
+
+        for (min, max, bits) in [
+            (vec![1, 2], vec![6, 5], 3),
+            (vec![0, 3], vec![12, 9], 4),
+            (vec![1, 0, 2], vec![3, 2, 7], 3),
+        ] {
+            let intervals: Vec<(u128, u128)> = hilbert::range_query(&min, &max, bits);
+            let size: u128 = 1 << (min.len() as u32 * bits);
+            let cells: Vec<u128> = (0..size)
+                .filter(|i: &u128| {
+                    let coords: Vec<u32> = hilbert::decode(*i, min.len(), bits);
+
+                    (0..min.len()).all(|d: usize| min[d] <= coords[d] && coords[d] <= max[d])
+                })
+                .collect();
+
+            // the intervals hold exactly the cells of the box
+            assert_eq!(cells, intervals.iter().flat_map(|(lo, hi)| *lo..=*hi).collect::<Vec<u128>>());
+            assert!(intervals.windows(2).all(|w| w[0].1 + 1 < w[1].0));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid number of bits")]
+    fn hilbert_invalid_bits_test() {
+        hilbert::encode(&[0, 0], 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid dimension")]
+    fn hilbert_invalid_dimension_test() {
+        hilbert::encode(&[0, 0, 0, 0, 0], 32);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid coordinate")]
+    fn hilbert_invalid_coordinate_test() {
+        hilbert::encode(&[4, 0], 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid range")]
+    fn hilbert_invalid_range_test() {
+        hilbert::range_query(&[0, 0], &[4, 0], 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid dimension")]
+    fn hilbert_invalid_range_dimension_test() {
+        hilbert::range_query(&[0; 32], &[1; 32], 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Too many intervals")]
+    fn hilbert_too_many_intervals_test() {
+        // each cell of the row is a block of its own
+        hilbert::range_query(&[0, 0], &[u32::MAX, 0], 32);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid index")]
+    fn hilbert_invalid_index_test() {
+        hilbert::decode(16, 2, 2);
+    }
+}
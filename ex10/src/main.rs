@@ -0,0 +1,3 @@
+fn main() {
+    println!("map(12, 34) ==> {}", ex10::map(12, 34));
+}
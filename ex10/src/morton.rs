@@ -0,0 +1,115 @@
+use crate::range;
+
+/// Encode N-dimensional coordinates (up to 4 dimensions) into their index on the Z-order curve
+/// # Arguments
+/// * `coords` - The coordinates, the first one holds the least significant bit of each group
+/// # Panics
+/// If there is no coordinate or more than 4 coordinates
+/// # Examples
+/// ```
+/// use ex10::morton::encode;
+///
+/// assert_eq!(0b1101, encode(&[3, 2]));
+/// assert_eq!(0b111, encode(&[1, 1, 1]));
+/// ```
+pub fn encode(coords: &[u32]) -> u128 {
+    let dims: usize = coords.len();
+    let mut res: u128 = 0;
+
+    if dims == 0 || dims > 4 {
+        panic!("Invalid dimension");
+    }
+    for i in 0..32 {
+        /*
+            the bit at index i of the coordinate d goes to the index
+            i * dims + d of the result, e.g. in 3D:
+            x = 0b11, y = 0b01, z = 0b00 => res = 0b001_011
+        */
+        for (d, c) in coords.iter().enumerate() {
+            if (c & (1 << i)) != 0 {
+                res |= 1 << (i * dims + d);
+            }
+        }
+    }
+
+    res
+}
+
+/// Decode an index on the Z-order curve into its N-dimensional coordinates
+/// # Arguments
+/// * `code` - The index on the curve
+/// * `dims` - The number of dimensions
+/// # Panics
+/// If the number of dimensions is not in [1, 4]
+/// # Examples
+/// ```
+/// use ex10::morton::{decode, encode};
+///
+/// assert_eq!(vec![3, 2], decode(0b1101, 2));
+/// assert_eq!(vec![7, 0, 42, 9], decode(encode(&[7, 0, 42, 9]), 4));
+/// ```
+pub fn decode(code: u128, dims: usize) -> Vec<u32> {
+    let mut res: Vec<u32> = vec![0; dims];
+
+    if dims == 0 || dims > 4 {
+        panic!("Invalid dimension");
+    }
+    for i in 0..32 {
+        for (d, c) in res.iter_mut().enumerate() {
+            if (code & (1 << (i * dims + d))) != 0 {
+                *c |= 1 << i;
+            }
+        }
+    }
+
+    res
+}
+
+/// Encode 3D coordinates into their index on the Z-order curve
+/// # Examples
+/// ```
+/// use ex10::morton::encode_3d;
+///
+/// assert_eq!(0b100_010_001, encode_3d(1, 2, 4));
+/// ```
+pub fn encode_3d(x: u32, y: u32, z: u32) -> u128 {
+    encode(&[x, y, z])
+}
+
+/// Decode an index on the Z-order curve into its 3D coordinates
+/// # Examples
+/// ```
+/// use ex10::morton::decode_3d;
+///
+/// assert_eq!((1, 2, 4), decode_3d(0b100_010_001));
+/// ```
+pub fn decode_3d(code: u128) -> (u32, u32, u32) {
+    let res: Vec<u32> = decode(code, 3);
+
+    (res[0], res[1], res[2])
+}
+
+/// Get the intervals of the Z-order curve covering the box [min, max],
+/// the intervals are sorted, inclusive and merged when they are adjacent
+/// # Arguments
+/// * `min` - The lowest corner of the box
+/// * `max` - The highest corner of the box
+/// # Panics
+/// If the corners don't have the same valid number of dimensions, if min > max or if the box
+/// is split into more than 65536 blocks
+/// # Examples
+/// ```
+/// use ex10::morton::range_query;
+///
+/// // the square [0, 1] x [0, 1] is a single block of the curve
+/// assert_eq!(vec![(0, 3)], range_query(&[0, 0], &[1, 1]));
+/// // the column x = 1, y in [0, 1] holds the cells 1 and 3
+/// assert_eq!(vec![(1, 1), (3, 3)], range_query(&[1, 0], &[1, 1]));
+/// ```
+pub fn range_query(min: &[u32], max: &[u32]) -> Vec<(u128, u128)> {
+    if min.is_empty() || min.len() > 4 {
+        panic!("Invalid dimension");
+    }
+
+    range::decompose(min, max, 32, encode)
+}
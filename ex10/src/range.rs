@@ -0,0 +1,96 @@
+const MAX_DIMS: usize = 16;
+const MAX_INTERVALS: usize = 1 << 16;
+
+/// Split the box [min, max] into the aligned blocks of a curve and get the intervals of their
+/// indexes, each aligned block of side 2 ^ level is visited by the Z-order and the Hilbert curves
+/// in a single run of 2 ^ (level * dims) consecutive indexes
+/// # Arguments
+/// * `min` - The lowest corner of the box
+/// * `max` - The highest corner of the box
+/// * `bits` - The number of bits of each coordinate
+/// * `encode` - The function giving the index of a cell on the curve
+/// # Panics
+/// If the corners don't have the same number of dimensions, if there are more than 16 of
+/// them, if the box is out of the grid or if it is split into more than 65536 blocks
+pub fn decompose(
+    min: &[u32],
+    max: &[u32],
+    bits: u32,
+    encode: impl Fn(&[u32]) -> u128,
+) -> Vec<(u128, u128)> {
+    let mut res: Vec<(u128, u128)> = Vec::new();
+
+    // a block is split into 2 ^ dims children, so the number of dimensions is kept small
+    if min.len() > MAX_DIMS {
+        panic!("Invalid dimension");
+    }
+    if min.len() != max.len()
+        || min.iter().zip(max.iter()).any(|(lo, hi)| lo > hi || (*hi as u64) >> bits != 0)
+    {
+        panic!("Invalid range");
+    }
+    visit_block(&vec![0; min.len()], bits, min, max, &encode, &mut res);
+    res.sort();
+
+    // merge the adjacent intervals
+    let mut merged: Vec<(u128, u128)> = Vec::new();
+
+    for (lo, hi) in res {
+        match merged.last_mut() {
+            Some(last) if last.1.checked_add(1) == Some(lo) => last.1 = hi,
+            _ => merged.push((lo, hi)),
+        }
+    }
+
+    merged
+}
+
+fn visit_block(
+    origin: &[u32],
+    level: u32,
+    min: &[u32],
+    max: &[u32],
+    encode: &impl Fn(&[u32]) -> u128,
+    res: &mut Vec<(u128, u128)>,
+) {
+    /*
+        The block holds the cells from origin to origin + side - 1 on each axis:
+        1- if the block is out of the box we skip it
+        2- if the block is inside the box we add the run of its indexes
+        3- otherwise we split it into 2 ^ dims blocks of half side
+     */
+    let side: u64 = 1 << level;
+    let mut inside: bool = true;
+
+    for d in 0..origin.len() {
+        let (lo, hi) = (origin[d] as u64, origin[d] as u64 + side - 1);
+
+        if lo > max[d] as u64 || hi < min[d] as u64 {
+            return;
+        }
+        inside &= lo >= min[d] as u64 && hi <= max[d] as u64;
+    }
+    if inside {
+        // a thin box can be split into a block per cell, so the output is bounded
+        if res.len() == MAX_INTERVALS {
+            panic!("Too many intervals");
+        }
+        let size: u32 = level * origin.len() as u32;
+        let mask: u128 = if size == 128 { u128::MAX } else { (1 << size) - 1 };
+        let start: u128 = encode(origin) & !mask;
+
+        res.push((start, start | mask));
+        return;
+    }
+    let half: u32 = 1 << (level - 1);
+
+    for child in 0..(1usize << origin.len()) {
+        let child_origin: Vec<u32> = origin
+            .iter()
+            .enumerate()
+            .map(|(d, o)| if (child & (1 << d)) != 0 { o + half } else { *o })
+            .collect();
+
+        visit_block(&child_origin, level - 1, min, max, encode, res);
+    }
+}
@@ -0,0 +1,149 @@
+/// Map the coordinates (x, y) to a unique value in [0, 1] using the Z-order curve
+/// # Arguments
+/// * `x` - The x coordinate
+/// * `y` - The y coordinate
+/// # Examples
+/// ```
+/// use ex11::map;
+///
+/// assert_eq!(0.0, map(0, 0));
+/// assert_eq!(1.0, map(u16::MAX, u16::MAX));
+/// ```
+pub fn map(x: u16, y: u16) -> f64 {
+    let mut res: u32 = 0;
+
+    for i in 0..16 {
+        /*
+            interleave the bits of x and y, the bit at index i
+            of x goes to the index 2 * i of the result and the bit
+            at index i of y goes to the index 2 * i + 1:
+            x = 0b11, y = 0b00 => res = 0b0101
+        */
+        if (x & (1 << i)) != 0 {
+            res |= 1 << (2 * i);
+        }
+        if (y & (1 << i)) != 0 {
+            res |= 1 << (2 * i + 1);
+        }
+    }
+
+    res as f64 / u32::MAX as f64
+}
+
+/// Get the coordinates (x, y) mapped to the given value, this is the inverse of `map`
+/// # Arguments
+/// * `n` - A value in [0, 1]
+/// # Panics
+/// If the value is out of [0, 1]
+/// # Examples
+/// ```
+/// use ex11::{map, reverse_map};
+///
+/// assert_eq!((0, 0), reverse_map(0.0));
+/// assert_eq!((u16::MAX, u16::MAX), reverse_map(1.0));
+/// assert_eq!((12, 34), reverse_map(map(12, 34)));
+/// ```
+pub fn reverse_map(n: f64) -> (u16, u16) {
+    if !(0.0..=1.0).contains(&n) {
+        panic!("Invalid value");
+    }
+    // round to the closest index to absorb the error of the division done by map
+    let z: u32 = (n * u32::MAX as f64).round() as u32;
+    let mut x: u16 = 0;
+    let mut y: u16 = 0;
+
+    for i in 0..16 {
+        /*
+            the bits at the even indexes belong to x
+            and the bits at the odd indexes belong to y
+        */
+        if (z & (1 << (2 * i))) != 0 {
+            x |= 1 << i;
+        }
+        if (z & (1 << (2 * i + 1))) != 0 {
+            y |= 1 << i;
+        }
+    }
+
+    (x, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subject_tests() {
+        assert_eq!((0, 0), reverse_map(0.0));
+        assert_eq!((u16::MAX, u16::MAX), reverse_map(1.0));
+        assert_eq!((12, 34), reverse_map(map(12, 34)));
+    }
+
+    #[test]
+    fn round_trip_small_square_tests() {
+        for x in 0..=u8::MAX as u16 {
+            for y in 0..=u8::MAX as u16 {
+                assert_eq!((x, y), reverse_map(map(x, y)));
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip_edges_tests() {
+        /*
+            Checking the 2 ^ 32 pairs is too slow for a test, the full axes are enough:
+            1- the bits of x and y are interleaved independently of each other, so a full
+               axis with the other coordinate fixed checks every bit of the moving one
+            2- the division by u32::MAX has a relative error under 2 ^ -52, so the product
+               in reverse_map is less than 2 ^ -20 away from the index and always rounds back
+        */
+        for i in 0..=u16::MAX {
+            assert_eq!((i, 0x5555), reverse_map(map(i, 0x5555)));
+            assert_eq!((0xAAAA, i), reverse_map(map(0xAAAA, i)));
+            assert_eq!((i, 0), reverse_map(map(i, 0)));
+            assert_eq!((0, i), reverse_map(map(0, i)));
+            assert_eq!((i, u16::MAX), reverse_map(map(i, u16::MAX)));
+            assert_eq!((u16::MAX, i), reverse_map(map(u16::MAX, i)));
+            assert_eq!((i, i), reverse_map(map(i, i)));
+            assert_eq!((i, u16::MAX - i), reverse_map(map(i, u16::MAX - i)));
+        }
+    }
+
+    #[test]
+    fn round_trip_grid_tests() {
+        for x in (0..=u16::MAX).step_by(251) {
+            for y in (0..=u16::MAX).step_by(241) {
+                assert_eq!((x, y), reverse_map(map(x, y)));
+            }
+        }
+    }
+
+    #[test]
+    fn reverse_round_trip_tests() {
+        // every u32 index is reached by map, so reverse_map then map gives back the same value
+        for z in (0..=u32::MAX).step_by(65_521).chain([1, u32::MAX - 1, u32::MAX]) {
+            let n: f64 = z as f64 / u32::MAX as f64;
+            let (x, y) = reverse_map(n);
+
+            assert_eq!(n, map(x, y));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid value")]
+    fn invalid_value_test1() {
+        reverse_map(-0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid value")]
+    fn invalid_value_test2() {
+        reverse_map(1.000001);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid value")]
+    fn invalid_value_test3() {
+        reverse_map(f64::NAN);
+    }
+}
@@ -0,0 +1,6 @@
+fn main() {
+    let n: f64 = ex11::map(12, 34);
+
+    println!("map(12, 34) ==> {}", n);
+    println!("reverse_map({}) ==> {:?}", n, ex11::reverse_map(n));
+}
@@ -0,0 +1,203 @@
+mod netlist;
+
+pub use netlist::{Cell, Netlist};
+
+/// Synthesize a formula into a structural Verilog module, the output of the module is named f
+/// # Arguments
+/// * `formula` - A string slice that holds the formula, mixing vars and the constants '1' and '0'
+/// * `module` - The name of the module
+/// # Example
+/// ```
+/// use netlist::verilog_netlist;
+///
+/// print!("{}", verilog_netlist("AB&C|", "top"));
+/// // Output:
+/// // module top(A, B, C, f);
+/// //   input A, B, C;
+/// //   output f;
+/// //   wire n2, n4;
+/// //   and g2(n2, A, B);
+/// //   or g4(n4, n2, C);
+/// //   assign f = n4;
+/// // endmodule
+/// ```
+/// # Panics
+/// If the formula is invalid
+pub fn verilog_netlist(formula: &str, module: &str) -> String {
+    Netlist::new(formula).to_verilog(module)
+}
+
+/// Synthesize a formula into a BLIF model, the output of the model is named f
+/// # Arguments
+/// * `formula` - A string slice that holds the formula, mixing vars and the constants '1' and '0'
+/// * `model` - The name of the model
+/// # Example
+/// ```
+/// use netlist::blif_netlist;
+///
+/// print!("{}", blif_netlist("AB&C|", "top"));
+/// // Output:
+/// // .model top
+/// // .inputs A B C
+/// // .outputs f
+/// // .names A B n2
+/// // 11 1
+/// // .names n2 C n4
+/// // 1- 1
+/// // -1 1
+/// // .names n4 f
+/// // 1 1
+/// // .end
+/// ```
+/// # Panics
+/// If the formula is invalid
+pub fn blif_netlist(formula: &str, model: &str) -> String {
+    Netlist::new(formula).to_blif(model)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Evaluate the formula with a stack of values, the vars take the values in alphabetical order
+    fn eval_formula(formula: &str, inputs: &[char], values: &[bool]) -> bool {
+        let mut stack: Vec<bool> = Vec::new();
+
+        for c in formula.chars() {
+            let value: bool = match c {
+                'A'..='Z' => values[inputs.binary_search(&c).unwrap()],
+                '1' | '0' => c == '1',
+                '!' => !stack.pop().unwrap(),
+                _ => {
+                    let (b, a) = (stack.pop().unwrap(), stack.pop().unwrap());
+
+                    match c {
+                        '&' => a & b,
+                        '|' => a | b,
+                        '^' => a ^ b,
+                        '>' => !a | b,
+                        '↑' => !(a & b),
+                        '↓' => !(a | b),
+                        _ => a == b,
+                    }
+                }
+            };
+
+            stack.push(value);
+        }
+
+        stack[0]
+    }
+
+    #[test]
+    fn netlist_sharing_tests() {
+        // the conjunction is built once for both operands
+        let netlist: Netlist = Netlist::new("AB&BA&|");
+        assert_eq!(2, netlist.gate_count());
+        assert_eq!(2, netlist.depth());
+        assert_eq!(vec!['A', 'B'], netlist.get_inputs());
+
+        let netlist: Netlist = Netlist::new("AB>C!AB>!&=");
+        // !A, !A | B, !C, !(!A | B), AND and XNOR
+        assert_eq!(6, netlist.gate_count());
+        assert_eq!(5, netlist.depth());
+
+        // the constants are cells too, but not gates
+        let netlist: Netlist = Netlist::new("10&01&|");
+        assert_eq!(2, netlist.gate_count());
+        assert!(netlist.get_inputs().is_empty());
+    }
+
+    #[test]
+    fn netlist_eval_tests() {
+        let formulas: [&str; 8] = ["AB&C|", "AB>C!AB>!&=", "AB↑C↓", "AB⊙CD^&", "AB|1&", "AB=C0|>", "A!!B!!&", "10↑1↓0⊙"];
+
+        for formula in formulas {
+            let netlist: Netlist = Netlist::new(formula);
+            let inputs: Vec<char> = netlist.get_inputs();
+
+            // compare each row of the truth table with the value of the formula
+            for mask in 0..(1 << inputs.len()) {
+                let values: Vec<bool> = (0..inputs.len()).map(|i: usize| mask & (1 << i) != 0).collect();
+
+                assert_eq!(eval_formula(formula, &inputs, &values), netlist.eval(&values), "{} {:?}", formula, values);
+            }
+        }
+    }
+
+    #[test]
+    fn netlist_export_tests() {
+        assert_eq!(
+            "module top(A, B, C, f);\n\
+             \x20 input A, B, C;\n\
+             \x20 output f;\n\
+             \x20 wire n2, n4;\n\
+             \x20 and g2(n2, A, B);\n\
+             \x20 or g4(n4, n2, C);\n\
+             \x20 assign f = n4;\n\
+             endmodule\n",
+            verilog_netlist("AB&C|", "top")
+        );
+        assert_eq!(
+            "module m(A, f);\n\
+             \x20 input A;\n\
+             \x20 output f;\n\
+             \x20 wire n1, n2, n3;\n\
+             \x20 assign n1 = 1'b0;\n\
+             \x20 nor g2(n2, A, n1);\n\
+             \x20 xnor g3(n3, A, n2);\n\
+             \x20 assign f = n3;\n\
+             endmodule\n",
+            verilog_netlist("A0↓A=", "m")
+        );
+        assert_eq!(
+            ".model top\n.inputs A B C\n.outputs f\n\
+             .names A B n2\n11 1\n\
+             .names n2 C n4\n1- 1\n-1 1\n\
+             .names n4 f\n1 1\n.end\n",
+            blif_netlist("AB&C|", "top")
+        );
+        assert_eq!(
+            ".model m\n.inputs A B\n.outputs f\n\
+             .names n0\n1\n\
+             .names A B n3\n01 1\n10 1\n\
+             .names n3 n4\n0 1\n\
+             .names n0 n4 n5\n0- 1\n-0 1\n\
+             .names n5 f\n1 1\n.end\n",
+            blif_netlist("1AB^!↑", "m")
+        );
+        // without vars the model has no inputs
+        assert_eq!(
+            ".model m\n.outputs f\n\
+             .names n0\n1\n\
+             .names n0 n1\n0 1\n\
+             .names n1 f\n1 1\n.end\n",
+            blif_netlist("1!", "m")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid formula")]
+    fn invalid_formula_empty_string_test() {
+        Netlist::new("");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid formula")]
+    fn invalid_formula_no_enough_operators_test() {
+        Netlist::new("AB&C");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid formula")]
+    fn invalid_formula_no_enough_values_test() {
+        Netlist::new("A&");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid formula")]
+    fn invalid_formula_unknown_symbol_test() {
+        // the sum of the multisets has no gate
+        Netlist::new("AB+");
+    }
+}
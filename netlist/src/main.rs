@@ -0,0 +1,6 @@
+use netlist::{blif_netlist, verilog_netlist};
+
+fn main() {
+    print!("{}", verilog_netlist("AB&C|", "top"));
+    print!("{}", blif_netlist("AB&C|", "top"));
+}
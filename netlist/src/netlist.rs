@@ -0,0 +1,317 @@
+use std::collections::{BTreeSet, HashMap};
+
+/// A cell of the netlist, the operands are the indexes of the cells driving its inputs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cell {
+    Input(char),
+    Const(bool),
+    Not(usize),
+    And(usize, usize),
+    Or(usize, usize),
+    Xor(usize, usize),
+    Nand(usize, usize),
+    Nor(usize, usize),
+    Xnor(usize, usize),
+}
+
+/// A gate-level netlist of a formula with a single output, the identical
+/// subexpressions are built once and shared by all the cells reading them
+#[derive(Debug, Clone)]
+pub struct Netlist {
+    cells: Vec<Cell>,
+    table: HashMap<Cell, usize>,
+    output: usize,
+}
+
+impl Netlist {
+    /// Synthesize the netlist of a formula in reverse polish notation, the vars are the
+    /// uppercase letters and the constants are '1' and '0'. The material condition
+    /// A > B is built as !A | B and the equivalence as a XNOR gate
+    /// # Arguments
+    /// * `formula` - A string slice that holds the formula
+    /// # Panics
+    /// If the formula is invalid
+    pub fn new(formula: &str) -> Self {
+        let mut netlist: Netlist = Self {
+            cells: Vec::new(),
+            table: HashMap::new(),
+            output: 0,
+        };
+        // the indexes of the cells driving the subexpressions not read yet
+        let mut stack: Vec<usize> = Vec::new();
+
+        for c in formula.chars() {
+            let cell: usize = match c {
+                'A'..='Z' => netlist.add_cell(Cell::Input(c)),
+                '1' | '0' => netlist.add_cell(Cell::Const(c == '1')),
+                '!' => {
+                    let a: usize = pop_operand(&mut stack);
+
+                    netlist.add_cell(Cell::Not(a))
+                }
+                '&' | '|' | '^' | '>' | '=' | '↑' | '↓' | '⊙' => {
+                    let b: usize = pop_operand(&mut stack);
+                    let a: usize = pop_operand(&mut stack);
+
+                    match c {
+                        '&' => netlist.add_cell(Cell::And(a, b)),
+                        '|' => netlist.add_cell(Cell::Or(a, b)),
+                        '^' => netlist.add_cell(Cell::Xor(a, b)),
+                        '↑' => netlist.add_cell(Cell::Nand(a, b)),
+                        '↓' => netlist.add_cell(Cell::Nor(a, b)),
+                        '=' | '⊙' => netlist.add_cell(Cell::Xnor(a, b)),
+                        _ => {
+                            // A > B <=> !A | B
+                            let not_a: usize = netlist.add_cell(Cell::Not(a));
+
+                            netlist.add_cell(Cell::Or(not_a, b))
+                        }
+                    }
+                }
+                _ => panic!("Invalid formula"),
+            };
+
+            stack.push(cell);
+        }
+        // a valid formula leaves a single subexpression, the whole formula
+        if stack.len() != 1 {
+            panic!("Invalid formula");
+        }
+        netlist.output = stack[0];
+
+        netlist
+    }
+
+    /// Get the cells of the netlist, each cell only reads the cells placed before it
+    pub fn get_cells(&self) -> &Vec<Cell> {
+        &self.cells
+    }
+
+    /// Get the index of the cell driving the output
+    pub fn get_output(&self) -> usize {
+        self.output
+    }
+
+    /// Get the inputs of the netlist in alphabetical order
+    pub fn get_inputs(&self) -> Vec<char> {
+        let inputs: BTreeSet<char> = self
+            .cells
+            .iter()
+            .filter_map(|cell: &Cell| match cell {
+                Cell::Input(c) => Some(*c),
+                _ => None,
+            })
+            .collect();
+
+        inputs.into_iter().collect()
+    }
+
+    /// Get the number of gates, the inputs and the constants are not counted
+    pub fn gate_count(&self) -> usize {
+        self.cells
+            .iter()
+            .filter(|cell: &&Cell| !matches!(cell, Cell::Input(_) | Cell::Const(_)))
+            .count()
+    }
+
+    /// Get the number of gates on the longest path from an input to the output
+    pub fn depth(&self) -> usize {
+        let mut depths: Vec<usize> = Vec::with_capacity(self.cells.len());
+
+        for cell in self.cells.iter() {
+            let depth: usize = match get_operands(cell) {
+                None => 0,
+                Some((lhs, None)) => depths[lhs] + 1,
+                Some((lhs, Some(rhs))) => depths[lhs].max(depths[rhs]) + 1,
+            };
+
+            depths.push(depth);
+        }
+
+        depths[self.output]
+    }
+
+    /// Evaluate the netlist
+    /// # Arguments
+    /// * `inputs` - The value of each input, in the order of get_inputs
+    /// # Panics
+    /// If the number of values doesn't match the number of inputs
+    pub fn eval(&self, inputs: &[bool]) -> bool {
+        let names: Vec<char> = self.get_inputs();
+
+        if names.len() != inputs.len() {
+            panic!("Invalid number of inputs");
+        }
+        let mut values: Vec<bool> = Vec::with_capacity(self.cells.len());
+
+        for cell in self.cells.iter() {
+            let value: bool = match *cell {
+                Cell::Input(c) => inputs[names.binary_search(&c).unwrap()],
+                Cell::Const(value) => value,
+                Cell::Not(a) => !values[a],
+                Cell::And(a, b) => values[a] & values[b],
+                Cell::Or(a, b) => values[a] | values[b],
+                Cell::Xor(a, b) => values[a] ^ values[b],
+                Cell::Nand(a, b) => !(values[a] & values[b]),
+                Cell::Nor(a, b) => !(values[a] | values[b]),
+                Cell::Xnor(a, b) => values[a] == values[b],
+            };
+
+            values.push(value);
+        }
+
+        values[self.output]
+    }
+
+    /// Export the netlist as a structural Verilog module using the gate primitives,
+    /// the inputs keep the names of the vars and the output is named f
+    /// # Arguments
+    /// * `module` - The name of the module
+    pub fn to_verilog(&self, module: &str) -> String {
+        let inputs: Vec<String> = self.get_inputs().iter().map(|c: &char| c.to_string()).collect();
+        let mut ports: Vec<String> = inputs.clone();
+        let wires: Vec<String> = (0..self.cells.len())
+            .filter(|i: &usize| !matches!(self.cells[*i], Cell::Input(_)))
+            .map(|i: usize| self.get_wire_name(i))
+            .collect();
+        let mut res: String = String::new();
+
+        ports.push("f".to_string());
+        res += &format!("module {}({});\n", module, ports.join(", "));
+        if !inputs.is_empty() {
+            res += &format!("  input {};\n", inputs.join(", "));
+        }
+        res += "  output f;\n";
+        if !wires.is_empty() {
+            res += &format!("  wire {};\n", wires.join(", "));
+        }
+        for (i, cell) in self.cells.iter().enumerate() {
+            let name: String = self.get_wire_name(i);
+
+            res += &match *cell {
+                Cell::Input(_) => continue,
+                Cell::Const(value) => format!("  assign {} = 1'b{};\n", name, value as u8),
+                Cell::Not(a) => format!("  not g{}({}, {});\n", i, name, self.get_wire_name(a)),
+                _ => {
+                    let (a, b) = get_operands(cell).map(|(a, b)| (a, b.unwrap())).unwrap();
+
+                    format!(
+                        "  {} g{}({}, {}, {});\n",
+                        get_primitive(cell),
+                        i,
+                        name,
+                        self.get_wire_name(a),
+                        self.get_wire_name(b),
+                    )
+                }
+            };
+        }
+        res += &format!("  assign f = {};\n", self.get_wire_name(self.output));
+        res += "endmodule\n";
+
+        res
+    }
+
+    /// Export the netlist as a BLIF model, each gate is a .names block holding
+    /// the rows of its truth table where the output is 1
+    /// # Arguments
+    /// * `model` - The name of the model
+    pub fn to_blif(&self, model: &str) -> String {
+        let inputs: Vec<String> = self.get_inputs().iter().map(|c: &char| c.to_string()).collect();
+        let mut res: String = String::new();
+
+        res += &format!(".model {}\n", model);
+        if !inputs.is_empty() {
+            res += &format!(".inputs {}\n", inputs.join(" "));
+        }
+        res += ".outputs f\n";
+        for (i, cell) in self.cells.iter().enumerate() {
+            let name: String = self.get_wire_name(i);
+            let operands: String = match get_operands(cell) {
+                None => String::new(),
+                Some((a, None)) => format!("{} ", self.get_wire_name(a)),
+                Some((a, Some(b))) => format!("{} {} ", self.get_wire_name(a), self.get_wire_name(b)),
+            };
+            let rows: &str = match cell {
+                Cell::Input(_) => continue,
+                Cell::Const(true) => "1\n",
+                Cell::Const(false) => "",
+                Cell::Not(_) => "0 1\n",
+                Cell::And(..) => "11 1\n",
+                Cell::Or(..) => "1- 1\n-1 1\n",
+                Cell::Xor(..) => "01 1\n10 1\n",
+                Cell::Nand(..) => "0- 1\n-0 1\n",
+                Cell::Nor(..) => "00 1\n",
+                Cell::Xnor(..) => "00 1\n11 1\n",
+            };
+
+            res += &format!(".names {}{}\n{}", operands, name, rows);
+        }
+        // a buffer connects the output to the cell driving it
+        res += &format!(".names {} f\n1 1\n", self.get_wire_name(self.output));
+        res += ".end\n";
+
+        res
+    }
+
+    /// Get the index of the cell, the cell is only added if the same one doesn't exist yet
+    fn add_cell(&mut self, cell: Cell) -> usize {
+        // all the binary gates are commutative, so the operands are sorted to share A & B and B & A
+        let cell: Cell = match cell {
+            Cell::And(a, b) => Cell::And(a.min(b), a.max(b)),
+            Cell::Or(a, b) => Cell::Or(a.min(b), a.max(b)),
+            Cell::Xor(a, b) => Cell::Xor(a.min(b), a.max(b)),
+            Cell::Nand(a, b) => Cell::Nand(a.min(b), a.max(b)),
+            Cell::Nor(a, b) => Cell::Nor(a.min(b), a.max(b)),
+            Cell::Xnor(a, b) => Cell::Xnor(a.min(b), a.max(b)),
+            _ => cell,
+        };
+
+        if let Some(index) = self.table.get(&cell) {
+            return *index;
+        }
+        self.cells.push(cell);
+        self.table.insert(cell, self.cells.len() - 1);
+
+        self.cells.len() - 1
+    }
+
+    fn get_wire_name(&self, index: usize) -> String {
+        match self.cells[index] {
+            Cell::Input(c) => c.to_string(),
+            _ => format!("n{}", index),
+        }
+    }
+}
+
+fn pop_operand(stack: &mut Vec<usize>) -> usize {
+    stack.pop().unwrap_or_else(|| {
+        panic!("Invalid formula");
+    })
+}
+
+fn get_operands(cell: &Cell) -> Option<(usize, Option<usize>)> {
+    match *cell {
+        Cell::Input(_) | Cell::Const(_) => None,
+        Cell::Not(a) => Some((a, None)),
+        Cell::And(a, b)
+        | Cell::Or(a, b)
+        | Cell::Xor(a, b)
+        | Cell::Nand(a, b)
+        | Cell::Nor(a, b)
+        | Cell::Xnor(a, b) => Some((a, Some(b))),
+    }
+}
+
+fn get_primitive(cell: &Cell) -> &'static str {
+    match cell {
+        Cell::Input(_) | Cell::Const(_) => "",
+        Cell::Not(_) => "not",
+        Cell::And(..) => "and",
+        Cell::Or(..) => "or",
+        Cell::Xor(..) => "xor",
+        Cell::Nand(..) => "nand",
+        Cell::Nor(..) => "nor",
+        Cell::Xnor(..) => "xnor",
+    }
+}
@@ -0,0 +1,100 @@
+pub mod relation;
+pub mod set;
+
+pub use relation::Relation;
+pub use set::Set;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn readme_example_test() {
+        let a = Set::from([1, 2, 3]);
+        let b = Set::from([3, 4, 5]);
+
+        assert_eq!(Set::from([1, 2, 3, 4, 5]), a.union(&b));
+        assert_eq!(Set::from([3]), a.intersection(&b));
+        assert_eq!("{1, 2, 3, 4, 5}", format!("{:?}", a.union(&b)));
+        assert_eq!("{}", format!("{:?}", Set::<i32>::new()));
+    }
+
+    #[test]
+    fn set_operations_test() {
+        let universe: Set<i32> = (0..10).collect();
+        let a: Set<i32> = Set::from([1, 2, 3, 4]);
+        let b: Set<i32> = Set::from([3, 4, 5, 3]);
+
+        assert_eq!(3, b.len());
+        assert_eq!(Set::from([1, 2]), a.difference(&b));
+        assert_eq!(Set::from([1, 2, 5]), a.symmetric_difference(&b));
+        assert_eq!(Set::from([0, 5, 6, 7, 8, 9]), a.complement(&universe));
+        assert_eq!(a.union(&b).complement(&universe), a.complement(&universe).intersection(&b.complement(&universe)));
+        assert!(a.intersection(&b).is_subset(&a) && universe.is_superset(&b));
+        assert!(a.is_disjoint(&Set::from([0, 9])) && !a.is_disjoint(&b));
+        assert!(Set::<i32>::default().is_empty());
+        assert_eq!(vec![1, 2, 3, 4], a.iter().copied().collect::<Vec<i32>>());
+        assert_eq!(10, (&a).into_iter().sum::<i32>());
+        assert_eq!(vec![1, 2, 3, 4], a.into_iter().collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn powerset_and_cartesian_product_test() {
+        let set: Set<u8> = (0..5).collect();
+        let subsets: Set<Set<u8>> = set.powerset();
+
+        assert_eq!(32, subsets.len());
+        assert!(subsets.iter().all(|subset: &Set<u8>| subset.is_subset(&set)));
+        assert_eq!(Set::from([Set::new()]), Set::<u8>::new().powerset());
+
+        let product: Set<(u8, char)> = set.cartesian_product(&Set::from(['a', 'b', 'c']));
+
+        assert_eq!(15, product.len());
+        assert!(product.contains(&(4, 'c')) && !product.contains(&(5, 'a')));
+        assert!(set.cartesian_product(&Set::<char>::new()).is_empty());
+    }
+
+    #[test]
+    fn set_builder_test() {
+        let universe: Set<u32> = (1..=30).collect();
+        let primes: Set<u32> = Set::from_predicate(&universe, |n: &u32| {
+            *n > 1 && (2..*n).all(|d: u32| !n.is_multiple_of(d))
+        });
+
+        assert_eq!(Set::from([2, 3, 5, 7, 11, 13, 17, 19, 23, 29]), primes);
+        assert_eq!(Set::from([4, 9, 25]), primes.map(|p: &u32| p * p).filter(|n: &u32| *n < 30));
+        let evens: Set<u32> = Set::from_predicate(&universe, |n: &u32| n.is_multiple_of(2));
+
+        assert_eq!(
+            universe.filter(|n: &u32| n.is_multiple_of(6)),
+            evens.intersection(&universe.filter(|n: &u32| n.is_multiple_of(3)))
+        );
+    }
+
+    #[test]
+    fn relation_test() {
+        let set: Set<i32> = Set::from([1, 2, 3, 4]);
+        let r: Relation<i32> = Relation::from([(1, 2), (2, 3), (3, 1)]);
+
+        assert_eq!(Set::from([1, 2, 3]), r.domain());
+        assert_eq!(Relation::from([(2, 1), (3, 2), (1, 3)]), r.inverse());
+        assert_eq!(Relation::from([(1, 3), (2, 1), (3, 2)]), r.compose(&r));
+        assert_eq!(r.compose(&r).compose(&r), Relation::from([(1, 1), (2, 2), (3, 3)]));
+        assert_eq!(9, r.transitive_closure().get_pairs().len());
+        assert!(r.transitive_closure().is_transitive() && !r.is_transitive());
+        assert!(!r.is_reflexive(&set) && r.reflexive_closure(&set).is_reflexive(&set));
+        assert_eq!(7, r.reflexive_closure(&set).get_pairs().len());
+        assert!(r.symmetric_closure().is_symmetric() && !r.is_symmetric());
+        assert!(r.is_antisymmetric() && !r.symmetric_closure().is_antisymmetric());
+        assert_eq!("{(1, 2), (2, 3), (3, 1)}", format!("{:?}", r));
+
+        // the reflexive transitive closure of the successor relation is the order
+        let successor: Relation<i32> = Relation::from_predicate(&set, |a: &i32, b: &i32| a + 1 == *b);
+        let order: Relation<i32> = Relation::from_predicate(&set, |a: &i32, b: &i32| a <= b);
+
+        assert_eq!(order, successor.transitive_closure().reflexive_closure(&set));
+        assert!(order.is_reflexive(&set) && order.is_antisymmetric() && order.is_transitive());
+        assert_eq!(order, successor.reflexive_closure(&set).transitive_closure());
+        assert_eq!(Set::from([2, 3, 4]), successor.range());
+    }
+}
@@ -0,0 +1,12 @@
+use set_theory::Set;
+
+fn main() {
+    let a = Set::from([1, 2, 3]);
+    let b = Set::from([3, 4, 5]);
+
+    let union = a.union(&b);
+    let intersection = a.intersection(&b);
+
+    println!("A ∪ B = {:?}", union);
+    println!("A ∩ B = {:?}", intersection);
+}
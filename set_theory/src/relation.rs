@@ -0,0 +1,161 @@
+use crate::Set;
+use std::fmt::{self, Debug};
+
+/// A binary relation on a set, stored as the set of its pairs: a R b when (a, b) is in it
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Relation<T: Ord> {
+    pairs: Set<(T, T)>,
+}
+
+impl<T: Ord + Clone> Relation<T> {
+    /// Get the relation holding the pairs
+    pub fn new(pairs: Set<(T, T)>) -> Self {
+        Self { pairs }
+    }
+
+    /// Get the relation of the pairs of the set satisfying the predicate,
+    /// which is { (a, b) ∈ A × A | P(a, b) }
+    /// # Examples
+    /// ```
+    /// use set_theory::{Relation, Set};
+    ///
+    /// let lower: Relation<i32> = Relation::from_predicate(&Set::from([1, 2, 3]), |a: &i32, b: &i32| a < b);
+    ///
+    /// assert_eq!(Relation::from([(1, 2), (1, 3), (2, 3)]), lower);
+    /// ```
+    pub fn from_predicate<P: Fn(&T, &T) -> bool>(set: &Set<T>, predicate: P) -> Self {
+        Self {
+            pairs: Set::from_predicate(&set.cartesian_product(set), |(a, b)| predicate(a, b)),
+        }
+    }
+
+    /// Get the pairs (a, b) of the relation
+    pub fn get_pairs(&self) -> &Set<(T, T)> {
+        &self.pairs
+    }
+
+    /// Check if a is related to b
+    pub fn contains(&self, a: &T, b: &T) -> bool {
+        self.pairs.contains(&(a.clone(), b.clone()))
+    }
+
+    /// Get the elements which are related to at least one element
+    pub fn domain(&self) -> Set<T> {
+        self.pairs.map(|(a, _)| a.clone())
+    }
+
+    /// Get the elements which at least one element is related to
+    pub fn range(&self) -> Set<T> {
+        self.pairs.map(|(_, b)| b.clone())
+    }
+
+    /// Get the relation holding the pairs of both relations
+    pub fn union(&self, other: &Relation<T>) -> Relation<T> {
+        Self::new(self.pairs.union(&other.pairs))
+    }
+
+    /// Get the inverse relation, where b is related to a when a is related to b
+    pub fn inverse(&self) -> Relation<T> {
+        Self::new(self.pairs.map(|(a, b)| (b.clone(), a.clone())))
+    }
+
+    /// Get the composition S ∘ R of this relation R and the other one S:
+    /// a is related to c when a R b and b S c for some b
+    /// # Examples
+    /// ```
+    /// use set_theory::Relation;
+    ///
+    /// let r: Relation<i32> = Relation::from([(1, 2), (2, 3)]);
+    /// let s: Relation<i32> = Relation::from([(2, 20), (3, 30)]);
+    ///
+    /// assert_eq!(Relation::from([(1, 20), (2, 30)]), r.compose(&s));
+    /// ```
+    pub fn compose(&self, other: &Relation<T>) -> Relation<T> {
+        let pairs: Set<(T, T)> = self
+            .pairs
+            .iter()
+            .flat_map(|(a, b)| {
+                other
+                    .pairs
+                    .iter()
+                    .filter(move |(c, _)| c == b)
+                    .map(move |(_, d)| (a.clone(), d.clone()))
+            })
+            .collect();
+
+        Self::new(pairs)
+    }
+
+    /// Get the smallest reflexive relation holding this one, every element
+    /// of the set is related to itself
+    /// # Arguments
+    /// * `set` - The set the relation is on
+    pub fn reflexive_closure(&self, set: &Set<T>) -> Relation<T> {
+        self.union(&Self::new(set.map(|x: &T| (x.clone(), x.clone()))))
+    }
+
+    /// Get the smallest symmetric relation holding this one
+    pub fn symmetric_closure(&self) -> Relation<T> {
+        self.union(&self.inverse())
+    }
+
+    /// Get the smallest transitive relation holding this one, a is related to c
+    /// when there is a path from a to c
+    /// # Examples
+    /// ```
+    /// use set_theory::Relation;
+    ///
+    /// let r: Relation<i32> = Relation::from([(1, 2), (2, 3), (3, 4)]);
+    ///
+    /// assert!(r.transitive_closure().contains(&1, &4));
+    /// assert_eq!(6, r.transitive_closure().get_pairs().len());
+    /// ```
+    pub fn transitive_closure(&self) -> Relation<T> {
+        let mut res: Relation<T> = self.clone();
+
+        // the paths double in length at each step, until no new pair is found
+        loop {
+            let next: Relation<T> = res.union(&res.compose(&res));
+
+            if next == res {
+                return res;
+            }
+            res = next;
+        }
+    }
+
+    /// Check if every element of the set is related to itself
+    /// # Arguments
+    /// * `set` - The set the relation is on
+    pub fn is_reflexive(&self, set: &Set<T>) -> bool {
+        set.iter().all(|x: &T| self.contains(x, x))
+    }
+
+    /// Check if b is related to a whenever a is related to b
+    pub fn is_symmetric(&self) -> bool {
+        *self == self.inverse()
+    }
+
+    /// Check if a and b are equal whenever they are related to each other
+    pub fn is_antisymmetric(&self) -> bool {
+        self.pairs.iter().all(|(a, b)| a == b || !self.contains(b, a))
+    }
+
+    /// Check if a is related to c whenever a R b and b R c
+    pub fn is_transitive(&self) -> bool {
+        self.compose(self).pairs.is_subset(&self.pairs)
+    }
+}
+
+impl<T: Ord + Clone, const N: usize> From<[(T, T); N]> for Relation<T> {
+    fn from(pairs: [(T, T); N]) -> Self {
+        Self::new(Set::from(pairs))
+    }
+}
+
+/// A relation is printed as the set of its pairs
+impl<T: Ord + Debug> Debug for Relation<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.pairs.fmt(f)
+    }
+}
@@ -0,0 +1,213 @@
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Debug},
+};
+
+/// An immutable finite set, the elements are kept sorted so two sets holding
+/// the same elements are equal and are printed the same way
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Set<T: Ord> {
+    elements: BTreeSet<T>,
+}
+
+impl<T: Ord> Set<T> {
+    /// Get the empty set
+    pub fn new() -> Self {
+        Self {
+            elements: BTreeSet::new(),
+        }
+    }
+
+    /// Get the set of the elements of the universe satisfying the predicate,
+    /// which is the set-builder notation { x ∈ U | P(x) }
+    /// # Arguments
+    /// * `universe` - The finite universe the elements are taken from
+    /// * `predicate` - The condition on the elements
+    /// # Examples
+    /// ```
+    /// use set_theory::Set;
+    ///
+    /// let universe: Set<u32> = (1..=10).collect();
+    /// let evens: Set<u32> = Set::from_predicate(&universe, |x: &u32| x % 2 == 0);
+    ///
+    /// assert_eq!(Set::from([2, 4, 6, 8, 10]), evens);
+    /// ```
+    pub fn from_predicate<P: Fn(&T) -> bool>(universe: &Set<T>, predicate: P) -> Self
+    where
+        T: Clone,
+    {
+        universe.iter().filter(|x: &&T| predicate(x)).cloned().collect()
+    }
+
+    /// Get the number of elements of the set
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Check if the set has no element
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Check if the element is in the set
+    pub fn contains(&self, x: &T) -> bool {
+        self.elements.contains(x)
+    }
+
+    /// Get an iterator over the elements in ascending order
+    pub fn iter(&self) -> std::collections::btree_set::Iter<'_, T> {
+        self.elements.iter()
+    }
+
+    /// Check if every element of the set is in the other one, A ⊆ B
+    pub fn is_subset(&self, other: &Set<T>) -> bool {
+        self.elements.is_subset(&other.elements)
+    }
+
+    /// Check if every element of the other set is in this one, A ⊇ B
+    pub fn is_superset(&self, other: &Set<T>) -> bool {
+        self.elements.is_superset(&other.elements)
+    }
+
+    /// Check if the sets have no element in common, A ∩ B = ∅
+    pub fn is_disjoint(&self, other: &Set<T>) -> bool {
+        self.elements.is_disjoint(&other.elements)
+    }
+}
+
+impl<T: Ord + Clone> Set<T> {
+    /// Get A ∪ B
+    pub fn union(&self, other: &Set<T>) -> Set<T> {
+        self.elements.union(&other.elements).cloned().collect()
+    }
+
+    /// Get A ∩ B
+    pub fn intersection(&self, other: &Set<T>) -> Set<T> {
+        self.elements.intersection(&other.elements).cloned().collect()
+    }
+
+    /// Get A \ B
+    pub fn difference(&self, other: &Set<T>) -> Set<T> {
+        self.elements.difference(&other.elements).cloned().collect()
+    }
+
+    /// Get A △ B, the elements which are in only one of the sets
+    pub fn symmetric_difference(&self, other: &Set<T>) -> Set<T> {
+        self.elements.symmetric_difference(&other.elements).cloned().collect()
+    }
+
+    /// Get U \ A, the complement of the set in the universe
+    pub fn complement(&self, universe: &Set<T>) -> Set<T> {
+        universe.difference(self)
+    }
+
+    /// Get the elements satisfying the predicate
+    pub fn filter<P: Fn(&T) -> bool>(&self, predicate: P) -> Set<T> {
+        Set::from_predicate(self, predicate)
+    }
+
+    /// Get the image of the set by the function, which is { f(x) | x ∈ A }
+    /// # Examples
+    /// ```
+    /// use set_theory::Set;
+    ///
+    /// assert_eq!(Set::from([0, 1]), Set::from([2, 3, 4]).map(|x: &i32| x % 2));
+    /// ```
+    pub fn map<U: Ord, F: Fn(&T) -> U>(&self, f: F) -> Set<U> {
+        self.iter().map(f).collect()
+    }
+
+    /// Get the set of all the subsets of the set, there are 2 ^ n of them
+    /// # Examples
+    /// ```
+    /// use set_theory::Set;
+    ///
+    /// let subsets: Set<Set<char>> = Set::from(['a', 'b']).powerset();
+    ///
+    /// assert_eq!(4, subsets.len());
+    /// assert!(subsets.contains(&Set::new()));
+    /// assert!(subsets.contains(&Set::from(['a', 'b'])));
+    /// ```
+    pub fn powerset(&self) -> Set<Set<T>> {
+        let mut subsets: Vec<Set<T>> = vec![Set::new()];
+
+        // each element doubles the subsets, the ones without it and the ones with it
+        for x in self.iter() {
+            let with_x: Vec<Set<T>> = subsets
+                .iter()
+                .map(|subset: &Set<T>| {
+                    let mut elements: BTreeSet<T> = subset.elements.clone();
+
+                    elements.insert(x.clone());
+                    Set { elements }
+                })
+                .collect();
+
+            subsets.extend(with_x);
+        }
+
+        subsets.into_iter().collect()
+    }
+
+    /// Get the Cartesian product A × B, the set of the pairs (a, b) with a ∈ A and b ∈ B
+    /// # Examples
+    /// ```
+    /// use set_theory::Set;
+    ///
+    /// let product: Set<(i32, char)> = Set::from([1, 2]).cartesian_product(&Set::from(['x']));
+    ///
+    /// assert_eq!(Set::from([(1, 'x'), (2, 'x')]), product);
+    /// ```
+    pub fn cartesian_product<U: Ord + Clone>(&self, other: &Set<U>) -> Set<(T, U)> {
+        self.iter()
+            .flat_map(|a: &T| other.iter().map(move |b: &U| (a.clone(), b.clone())))
+            .collect()
+    }
+}
+
+impl<T: Ord> Default for Set<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord, const N: usize> From<[T; N]> for Set<T> {
+    fn from(elements: [T; N]) -> Self {
+        Self {
+            elements: BTreeSet::from(elements),
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for Set<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            elements: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: Ord> IntoIterator for Set<T> {
+    type Item = T;
+    type IntoIter = std::collections::btree_set::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.into_iter()
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a Set<T> {
+    type Item = &'a T;
+    type IntoIter = std::collections::btree_set::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.iter()
+    }
+}
+
+/// A set is printed as {1, 2, 3}
+impl<T: Ord + Debug> Debug for Set<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.elements.iter()).finish()
+    }
+}